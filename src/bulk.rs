@@ -0,0 +1,57 @@
+use std::future::Future;
+
+use futures_util::{stream, StreamExt};
+
+use crate::err::Result;
+use crate::JellyfinClient;
+
+/// The number of requests `for_each_item_concurrent` keeps in flight when no limit is given.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+impl JellyfinClient {
+    /// Runs an operation for each of many items, with a bounded number of them in flight at once.
+    ///
+    /// This is meant for bulk tooling, such as marking thousands of items as played, where firing
+    /// every request at once would overwhelm the server. Operations run concurrently and may
+    /// finish in any order, but the results are returned in the order of `ids`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the items to operate on.
+    /// * `concurrency` - The maximum number of operations in flight, or `None` for `DEFAULT_CONCURRENCY`.
+    /// * `operation` - The operation to run for each item ID, typically a call on this client.
+    ///
+    /// # Returns
+    ///
+    /// A vector with the `Result` of the operation for each item, in the order of `ids`.
+    pub async fn for_each_item_concurrent<I, T, F, Fut, R>(
+        &self,
+        ids: I,
+        concurrency: Option<usize>,
+        mut operation: F,
+    ) -> Vec<Result<R>>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+        F: FnMut(String) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        let concurrency = concurrency.unwrap_or(DEFAULT_CONCURRENCY).max(1);
+
+        let mut results: Vec<(usize, Result<R>)> = stream::iter(ids.into_iter().enumerate())
+            .map(|(index, id)| {
+                let operation = operation(id.into());
+                async move { (index, operation.await) }
+            })
+            .buffer_unordered(concurrency)
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/bulk.rs"]
+mod tests;
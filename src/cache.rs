@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use reqwest::{Method, Response, StatusCode};
+use url::Url;
+
+use crate::err::Result;
+use crate::TOKEN_QUERY_PARAMS;
+
+/// How `JellyfinClient::with_cache` caches responses.
+///
+/// Only successful `GET` responses of the endpoints used to browse the library are cached: items,
+/// latest items, similar items, ancestors, genres, studios, people, artists, views, seasons and
+/// episodes. Playback, session and system information is always fetched from the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// How long a response is served from the cache.
+    pub ttl: Duration,
+    /// The most responses kept; the least recently used one is dropped to make room.
+    pub max_entries: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(60),
+            max_entries: 256,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    content_type: Option<HeaderValue>,
+    body: Bytes,
+    stored_at: Instant,
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<String, CacheEntry>,
+    /// Incremented on every access, to find the least recently used entry.
+    clock: u64,
+}
+
+/// An in-memory LRU cache of response bodies, shared by the clones of a client.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    config: CacheConfig,
+    state: Mutex<CacheState>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// The key a request is cached under, or `None` if it is not cached.
+    ///
+    /// The key is made of the identity of the authenticated user, so users never see each
+    /// other's results, and of the URL without its access token.
+    pub(crate) fn key(identity: &str, method: &Method, url: &Url) -> Option<String> {
+        if *method != Method::GET || !is_cached_path(url.path()) {
+            return None;
+        }
+
+        let mut url = url.clone();
+        let query: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(key, _)| !TOKEN_QUERY_PARAMS.contains(&key.to_ascii_lowercase().as_str()))
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        url.set_query(None);
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+
+        Some(format!("{} {}", identity, url))
+    }
+
+    /// Whether a successful request may change cached responses, so the cache must be cleared.
+    pub(crate) fn is_invalidated_by(method: &Method, url: &Url) -> bool {
+        *method != Method::GET && changes_cached_data(url.path())
+    }
+
+    /// Returns a copy of the cached response, if it has not expired.
+    pub(crate) fn get(&self, key: &str) -> Option<Response> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.clock += 1;
+        let clock = state.clock;
+
+        let entry = state.entries.get_mut(key)?;
+        if entry.stored_at.elapsed() > self.config.ttl {
+            state.entries.remove(key);
+            return None;
+        }
+        entry.last_used = clock;
+
+        Some(build_response(
+            entry.content_type.clone(),
+            entry.body.clone(),
+        ))
+    }
+
+    /// Reads a successful response into the cache, and returns a copy of it.
+    pub(crate) async fn store(&self, key: String, response: Response) -> Result<Response> {
+        let content_type = response.headers().get(CONTENT_TYPE).cloned();
+        let body = response.bytes().await?;
+
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.clock += 1;
+        let clock = state.clock;
+
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.config.max_entries {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                state.entries.remove(&oldest);
+            }
+        }
+        if self.config.max_entries > 0 {
+            state.entries.insert(
+                key,
+                CacheEntry {
+                    content_type: content_type.clone(),
+                    body: body.clone(),
+                    stored_at: Instant::now(),
+                    last_used: clock,
+                },
+            );
+        }
+
+        Ok(build_response(content_type, body))
+    }
+
+    /// Drops every cached response.
+    pub(crate) fn clear(&self) {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entries
+            .clear();
+    }
+}
+
+fn build_response(content_type: Option<HeaderValue>, body: Bytes) -> Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = StatusCode::OK;
+    if let Some(content_type) = content_type {
+        response.headers_mut().insert(CONTENT_TYPE, content_type);
+    }
+
+    response.into()
+}
+
+/// Whether the responses of a path are cached: the browsing endpoints listed on `CacheConfig`.
+fn is_cached_path(path: &str) -> bool {
+    let path = path.to_ascii_lowercase();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    match segments.as_slice() {
+        // Counts and suggestions change with every item added or played.
+        ["items", "counts" | "suggestions"] => false,
+        ["items"]
+        | ["items", _]
+        | ["items", _, "similar" | "ancestors"]
+        | ["genres" | "musicgenres" | "studios" | "persons" | "artists"]
+        | ["genres" | "musicgenres" | "studios" | "persons" | "artists", _]
+        | ["userviews"]
+        | ["shows", _, "seasons" | "episodes"]
+        | ["users", _, "items"]
+        | ["users", _, "items", _]
+        | ["users", _, "views"] => true,
+        _ => false,
+    }
+}
+
+/// Whether a request that is not a `GET` may change what the cached endpoints return: edits to
+/// items, user data, users and libraries. Playback reports, capabilities, remote searches and
+/// playback info leave them as they are.
+fn changes_cached_data(path: &str) -> bool {
+    let path = path.to_ascii_lowercase();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    match segments.as_slice() {
+        ["items", _, "playbackinfo"] | ["items", "remotesearch", _] => false,
+        ["sessions", "playing", "stopped"] => true,
+        ["items" | "useritems" | "users" | "library" | "videos" | "collections" | "playlists", ..] => {
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/cache.rs"]
+mod tests;
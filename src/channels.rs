@@ -0,0 +1,85 @@
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::{BaseItemDto, QueryResult};
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ChannelsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_latest_items: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_favorite: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ChannelItemsQuery {
+    /// The folder to list. Channels expose their content as virtual folders, so browsing a channel
+    /// starts without a folder and drills down using the ids of the returned folder items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+impl JellyfinClient {
+    /// Gets the channels provided by the server's plugins.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Filters and paging for the channel list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_channels(&self, query: &ChannelsQuery) -> Result<QueryResult<BaseItemDto>> {
+        let endpoint_url = self.url.join("/Channels").expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets the items of a channel, or of one of its folders.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_id` - The ID of the channel to browse.
+    /// * `query` - The folder to list, along with paging options.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_channel_items<T: Into<String>>(
+        &self,
+        channel_id: T,
+        query: &ChannelItemsQuery,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Channels/{}/Items", channel_id.into()))
+            .expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+}
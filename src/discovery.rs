@@ -0,0 +1,81 @@
+//! Finding Jellyfin servers on the local network.
+//!
+//! Requires the `discovery` feature.
+
+use std::collections::HashSet;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use tokio::net::UdpSocket;
+use tokio::time::{timeout_at, Instant};
+
+use crate::err::Result;
+
+/// The UDP port Jellyfin servers listen on for discovery requests.
+pub const DISCOVERY_PORT: u16 = 7359;
+
+const DISCOVERY_MESSAGE: &[u8] = b"Who is JellyfinServer?";
+
+/// A server that answered a discovery request.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DiscoveredServer {
+    /// The URL the server can be reached at, e.g. `http://192.168.1.10:8096`.
+    pub address: String,
+    pub name: String,
+    pub id: String,
+}
+
+/// Broadcasts a discovery request on the local network and collects the servers that answer.
+///
+/// Waits for answers until `timeout` has elapsed, and returns what was received by then, which may
+/// be nothing.
+///
+/// # Arguments
+///
+/// * `timeout` - How long to wait for answers.
+///
+/// # Returns
+///
+/// A `Result` wrapping the servers that answered if successful, or a `JellyfinError` if the broadcast could not be sent.
+pub async fn discover_servers(timeout: Duration) -> Result<Vec<DiscoveredServer>> {
+    discover_servers_at(
+        SocketAddr::from((Ipv4Addr::BROADCAST, DISCOVERY_PORT)),
+        timeout,
+    )
+    .await
+}
+
+async fn discover_servers_at(
+    target: SocketAddr,
+    timeout: Duration,
+) -> Result<Vec<DiscoveredServer>> {
+    let deadline = Instant::now() + timeout;
+
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+    socket.set_broadcast(true)?;
+    socket.send_to(DISCOVERY_MESSAGE, target).await?;
+
+    let mut servers = Vec::new();
+    let mut seen = HashSet::new();
+    let mut buf = [0; 4096];
+
+    while let Ok(received) = timeout_at(deadline, socket.recv_from(&mut buf)).await {
+        let (len, _) = received?;
+
+        // Anything else listening on the port may answer too, so malformed replies are skipped.
+        if let Ok(server) = serde_json::from_slice::<DiscoveredServer>(&buf[..len]) {
+            if seen.insert(server.id.clone()) {
+                servers.push(server);
+            }
+        }
+    }
+
+    Ok(servers)
+}
+
+#[cfg(test)]
+#[path = "tests/discovery.rs"]
+mod tests;
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::SortOrder;
+use crate::JellyfinClient;
+
+/// How a user last chose to display a library or view, stored on the server per client.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DisplayPreferences {
+    pub id: Option<String>,
+    /// The layout, e.g. `"Poster"` or `"List"`.
+    pub view_type: Option<String>,
+    pub sort_by: Option<String>,
+    pub index_by: Option<String>,
+    pub remember_indexing: bool,
+    pub primary_image_height: i64,
+    pub primary_image_width: i64,
+    /// Client-defined settings. The server allows values to be null.
+    #[serde(default)]
+    pub custom_prefs: HashMap<String, Option<String>>,
+    pub scroll_direction: ScrollDirection,
+    pub show_backdrop: bool,
+    pub remember_sorting: bool,
+    pub sort_order: SortOrder,
+    pub show_sidebar: bool,
+    pub client: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScrollDirection {
+    Horizontal,
+    #[default]
+    Vertical,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DisplayPreferencesQuery {
+    user_id: String,
+    client: String,
+}
+
+impl JellyfinClient {
+    /// Gets the display preferences a user saved for a view.
+    ///
+    /// # Arguments
+    ///
+    /// * `pref_id` - The ID of the preferences, usually the ID of the view they apply to.
+    /// * `user_id` - The ID of the user.
+    /// * `client_name` - The client the preferences belong to, e.g. `"emby"` for the web client.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `DisplayPreferences` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_display_preferences<T: Into<String>>(
+        &self,
+        pref_id: T,
+        user_id: T,
+        client_name: T,
+    ) -> Result<DisplayPreferences> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/DisplayPreferences/{}", pref_id.into()))
+            .expect("Failed to join URL");
+
+        let query = DisplayPreferencesQuery {
+            user_id: user_id.into(),
+            client: client_name.into(),
+        };
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(&query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Saves the display preferences of a user for a view.
+    ///
+    /// # Arguments
+    ///
+    /// * `pref_id` - The ID of the preferences, usually the ID of the view they apply to.
+    /// * `user_id` - The ID of the user.
+    /// * `client_name` - The client the preferences belong to, e.g. `"emby"` for the web client.
+    /// * `preferences` - The preferences to save.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn update_display_preferences<T: Into<String>>(
+        &self,
+        pref_id: T,
+        user_id: T,
+        client_name: T,
+        preferences: &DisplayPreferences,
+    ) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/DisplayPreferences/{}", pref_id.into()))
+            .expect("Failed to join URL");
+
+        let query = DisplayPreferencesQuery {
+            user_id: user_id.into(),
+            client: client_name.into(),
+        };
+
+        self.send_authed(
+            self.client
+                .post(endpoint_url)
+                .query(&query)
+                .json(preferences),
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/display_preferences.rs"]
+mod tests;
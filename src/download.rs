@@ -0,0 +1,174 @@
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+use reqwest::{header, Response};
+use url::Url;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::utils::forbidden_as;
+use crate::JellyfinClient;
+
+const DOWNLOAD_FORBIDDEN: &str = "Downloading is disabled for this user";
+
+/// The byte range of a partial response, as reported by the `Content-Range` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentRange {
+    /// The first byte of the range, inclusive.
+    pub start: u64,
+    /// The last byte of the range, inclusive.
+    pub end: u64,
+    /// The size of the whole file, if the server reported it.
+    pub total: Option<u64>,
+}
+
+impl ContentRange {
+    /// Parses a `Content-Range` header value such as `bytes 0-99/1000` or `bytes 0-99/*`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let (range, total) = value.strip_prefix("bytes ")?.split_once('/')?;
+        let (start, end) = range.split_once('-')?;
+
+        Some(Self {
+            start: start.trim().parse().ok()?,
+            end: end.trim().parse().ok()?,
+            total: match total.trim() {
+                "*" => None,
+                total => Some(total.parse().ok()?),
+            },
+        })
+    }
+}
+
+/// The body of a ranged download, along with the range the server actually returned.
+///
+/// `PartialDownload` is a stream of the body's bytes.
+pub struct PartialDownload {
+    /// The returned range, or `None` if the server ignored the `Range` header and sent the whole
+    /// file.
+    pub content_range: Option<ContentRange>,
+    /// The length of the returned body, if known.
+    pub content_length: Option<u64>,
+    body: Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>,
+}
+
+impl fmt::Debug for PartialDownload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PartialDownload")
+            .field("content_range", &self.content_range)
+            .field("content_length", &self.content_length)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Stream for PartialDownload {
+    type Item = Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.body.as_mut().poll_next(cx)
+    }
+}
+
+pub(crate) fn body_stream(response: Response) -> impl Stream<Item = Result<Bytes>> {
+    response
+        .bytes_stream()
+        .map(|chunk| chunk.map_err(JellyfinError::NetworkError))
+}
+
+impl JellyfinClient {
+    /// Builds the URL of the original file of an item.
+    ///
+    /// The URL carries the current access token, so it can be handed to an external downloader.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item to download.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the download `Url` if successful, or `JellyfinError::AuthNotFound` if the client is not authenticated.
+    pub fn download_item_url<T: Into<String>>(&self, item_id: T) -> Result<Url> {
+        self.authed_url(&format!("/Items/{}/Download", item_id.into()), &())
+    }
+
+    /// Downloads the original file of an item.
+    ///
+    /// The body is streamed as it arrives instead of being buffered, so large files can be written
+    /// to disk without holding them in memory. Requires the user to be allowed to download content.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item to download.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a stream of the file's bytes if successful, `JellyfinError::Forbidden` if downloading is disabled for the user, or another `JellyfinError` otherwise.
+    pub async fn download_item<T: Into<String>>(
+        &self,
+        item_id: T,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let response = self.send_download(item_id.into(), None).await?;
+
+        Ok(body_stream(response))
+    }
+
+    /// Downloads a byte range of the original file of an item, for example to resume an
+    /// interrupted download.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item to download.
+    /// * `start` - The first byte to download.
+    /// * `end` - The last byte to download, inclusive, or `None` to download until the end of the file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `PartialDownload` if successful, `JellyfinError::Forbidden` if downloading is disabled for the user, or another `JellyfinError` otherwise.
+    pub async fn download_item_range<T: Into<String>>(
+        &self,
+        item_id: T,
+        start: u64,
+        end: Option<u64>,
+    ) -> Result<PartialDownload> {
+        let range = match end {
+            Some(end) => format!("bytes={}-{}", start, end),
+            None => format!("bytes={}-", start),
+        };
+
+        let response = self.send_download(item_id.into(), Some(range)).await?;
+
+        let content_range = response
+            .headers()
+            .get(header::CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(ContentRange::parse);
+
+        Ok(PartialDownload {
+            content_range,
+            content_length: response.content_length(),
+            body: Box::pin(body_stream(response)),
+        })
+    }
+
+    async fn send_download(&self, item_id: String, range: Option<String>) -> Result<Response> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/{}/Download", item_id))
+            .expect("Failed to join URL");
+
+        let mut request = self.client.get(endpoint_url).header(header::ACCEPT, "*/*");
+        if let Some(range) = range {
+            request = request.header(header::RANGE, range);
+        }
+
+        self.send_authed(request)
+            .await
+            .map_err(|err| forbidden_as(err, DOWNLOAD_FORBIDDEN))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/download.rs"]
+mod tests;
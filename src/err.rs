@@ -6,7 +6,31 @@ pub type Result<T> = std::result::Result<T, JellyfinError>;
 pub enum JellyfinError {
     NetworkError(reqwest::Error),
     UrlParseError(url::ParseError),
+    /// A JSON document could not be converted to or from one of the crate's types.
+    JsonError(serde_json::Error),
+    /// The WebSocket connection to the server failed.
+    WebSocketError(Box<tokio_tungstenite::tungstenite::Error>),
+    /// A socket operation outside of HTTP failed, such as broadcasting a server discovery request.
+    IoError(std::io::Error),
     AuthNotFound,
+    /// The requested resource does not exist, for endpoints where a missing resource is an
+    /// expected outcome (such as a track without lyrics) rather than a failure.
+    NotFound(String),
+    /// The server refused an operation the authenticated user has no permission for, such as an
+    /// administrative operation or downloading when downloads are disabled for the user.
+    Forbidden(String),
+    /// The request cannot be served the way it was asked for, such as direct playing a media source
+    /// that needs transcoding.
+    Unsupported(String),
+    /// An argument was rejected before any request was made, such as an empty tag. The server was
+    /// not contacted.
+    InvalidInput(String),
+    /// More items matched a query than the caller allowed to fetch, such as with `get_all_items`.
+    /// The server was contacted, but nothing past the first page was fetched.
+    TooManyItems {
+        matched: usize,
+        max: usize,
+    },
     HttpRequestError {
         status: u16,
         type_: Option<String>, // Using type_ because `type` is a reserved keyword in Rust
@@ -16,15 +40,63 @@ pub enum JellyfinError {
         property1: Option<String>,
         property2: Option<String>,
         message: String, // To hold a simple error message or non-JSON response body
+        /// The body of the response exactly as the server sent it, or `None` when the error was
+        /// not built from a response.
+        body: Option<String>,
     },
 }
 
+impl JellyfinError {
+    /// Builds an `HttpRequestError` with the given status and message, and no problem details.
+    ///
+    /// Useful to construct representative errors in tests without a server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jellyfin_rs::err::JellyfinError;
+    ///
+    /// let err = JellyfinError::http(503, "Server is starting up");
+    /// assert!(matches!(err, JellyfinError::HttpRequestError { status: 503, .. }));
+    /// ```
+    pub fn http(status: u16, message: impl Into<String>) -> Self {
+        Self::HttpRequestError {
+            status,
+            type_: None,
+            title: None,
+            detail: None,
+            instance: None,
+            property1: None,
+            property2: None,
+            message: message.into(),
+            body: None,
+        }
+    }
+
+    /// Builds a `NotFound` error with the given message.
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::NotFound(message.into())
+    }
+}
+
 impl fmt::Display for JellyfinError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::NetworkError(v) => write!(f, "{}", v),
             Self::UrlParseError(v) => write!(f, "{}", v),
+            Self::JsonError(v) => write!(f, "{}", v),
+            Self::WebSocketError(v) => write!(f, "{}", v),
+            Self::IoError(v) => write!(f, "{}", v),
             Self::AuthNotFound => write!(f, "Unauthorized."),
+            Self::NotFound(v) => write!(f, "Not found: {}", v),
+            Self::Forbidden(v) => write!(f, "Forbidden: {}", v),
+            Self::Unsupported(v) => write!(f, "Unsupported: {}", v),
+            Self::InvalidInput(v) => write!(f, "Invalid input: {}", v),
+            Self::TooManyItems { matched, max } => write!(
+                f,
+                "Too many items: {} match the query, more than the maximum of {}",
+                matched, max
+            ),
             Self::HttpRequestError {
                 status,
                 type_,
@@ -34,6 +106,7 @@ impl fmt::Display for JellyfinError {
                 property1,
                 property2,
                 message,
+                ..
             } => {
                 write!(f, "HTTP Request Error (Status {}): {}", status, message)?;
                 if let Some(t) = type_ {
@@ -65,8 +138,17 @@ impl std::error::Error for JellyfinError {
         match self {
             Self::NetworkError(e) => Some(e),
             Self::UrlParseError(e) => Some(e),
+            Self::JsonError(e) => Some(e),
+            Self::WebSocketError(e) => Some(e),
+            Self::IoError(e) => Some(e),
             // AuthNotFound does not wrap another error, so we return None
-            Self::AuthNotFound | Self::HttpRequestError { .. } => None,
+            Self::AuthNotFound
+            | Self::NotFound(_)
+            | Self::Forbidden(_)
+            | Self::Unsupported(_)
+            | Self::InvalidInput(_)
+            | Self::TooManyItems { .. }
+            | Self::HttpRequestError { .. } => None,
         }
     }
 }
@@ -82,3 +164,21 @@ impl From<url::ParseError> for JellyfinError {
         Self::UrlParseError(value)
     }
 }
+
+impl From<serde_json::Error> for JellyfinError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::JsonError(value)
+    }
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for JellyfinError {
+    fn from(value: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::WebSocketError(Box::new(value))
+    }
+}
+
+impl From<std::io::Error> for JellyfinError {
+    fn from(value: std::io::Error) -> Self {
+        Self::IoError(value)
+    }
+}
@@ -0,0 +1,78 @@
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::{BaseItemDto, ItemFields, ItemType, QueryResult};
+use crate::serde::comma_separated;
+use crate::utils::UserIdQuery;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GenresQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// Only returns genres used in this library or folder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// Only returns genres used by these item types, e.g. `Movie` for movie genres.
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub include_item_types: Vec<ItemType>,
+    /// Additional fields to return. With `ItemCounts`, each genre carries the number of items the
+    /// user can access in it as its `child_count`.
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<ItemFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+impl JellyfinClient {
+    /// Gets the genres of the items in the library.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Filters and paging for the genre list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_genres(&self, query: &GenresQuery) -> Result<QueryResult<BaseItemDto>> {
+        let endpoint_url = self.url.join("/Genres").expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets a single genre by name, including its image tags.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the genre, e.g. `"Science Fiction"`.
+    /// * `user_id` - The ID of the user requesting the genre.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the genre as a `BaseItemDto` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_genre<T: Into<String>>(&self, name: T, user_id: T) -> Result<BaseItemDto> {
+        let endpoint_url = self.item_by_name_url("Genres", &name.into());
+
+        let query = UserIdQuery {
+            user_id: user_id.into(),
+        };
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(&query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/genres.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+use std::fmt;
+
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+/// Declares a newtype around the `String` ID of a kind of server object, convertible from and
+/// into strings so it can be passed wherever a plain ID was accepted.
+macro_rules! id_type {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(pub String);
+
+        impl $name {
+            /// The ID as sent to the server.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<&String> for $name {
+            fn from(value: &String) -> Self {
+                Self(value.clone())
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                Self(value.to_string())
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(value: &$name) -> Self {
+                value.clone()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+    };
+}
+
+id_type!(
+    /// The ID of a user.
+    ///
+    /// Methods taking a `UserId` also accept a `&str` or `String`; holding IDs as `UserId` lets the
+    /// compiler reject an `ItemId` passed by mistake.
+    UserId
+);
+
+id_type!(
+    /// The ID of an item, such as a movie, episode, folder or library.
+    ///
+    /// Methods taking an `ItemId` also accept a `&str` or `String`; holding IDs as `ItemId` lets the
+    /// compiler reject a `UserId` passed by mistake.
+    ItemId
+);
+
+#[cfg(test)]
+#[path = "tests/ids.rs"]
+mod tests;
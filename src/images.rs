@@ -0,0 +1,220 @@
+use std::convert::Infallible;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use bytes::Bytes;
+use futures_util::stream;
+use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE};
+use reqwest::Body;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::ImageType;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RemoteImageQuery {
+    #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<ImageType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Only returns images from the given metadata provider, e.g. `"TheMovieDb"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_name: Option<String>,
+    /// Also returns images in other languages than the item's metadata language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_all_languages: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RemoteImageResult {
+    #[serde(default)]
+    pub images: Vec<RemoteImageInfo>,
+    pub total_record_count: i64,
+    /// The providers that images are available from.
+    #[serde(default)]
+    pub providers: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RemoteImageInfo {
+    pub provider_name: Option<String>,
+    pub url: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub height: Option<i64>,
+    pub width: Option<i64>,
+    pub community_rating: Option<f64>,
+    pub vote_count: Option<i64>,
+    pub language: Option<String>,
+    #[serde(rename = "Type")]
+    pub type_: Option<ImageType>,
+}
+
+/// The size of the chunks an uploaded image is sent in, between two progress reports.
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct DownloadRemoteImageQuery {
+    #[serde(rename = "Type")]
+    type_: ImageType,
+    image_url: String,
+}
+
+impl JellyfinClient {
+    /// Gets the images the metadata providers offer for an item.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item.
+    /// * `query` - Filters and paging for the image list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `RemoteImageResult` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_remote_images<T: Into<String>>(
+        &self,
+        item_id: T,
+        query: &RemoteImageQuery,
+    ) -> Result<RemoteImageResult> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/{}/RemoteImages", item_id.into()))
+            .expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Downloads a metadata provider image and sets it as an image of an item.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item.
+    /// * `image_type` - The image to replace.
+    /// * `image_url` - The URL of the image, as returned by `get_remote_images`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn download_remote_image<T: Into<String>>(
+        &self,
+        item_id: T,
+        image_type: ImageType,
+        image_url: T,
+    ) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/{}/RemoteImages/Download", item_id.into()))
+            .expect("Failed to join URL");
+
+        let query = DownloadRemoteImageQuery {
+            type_: image_type,
+            image_url: image_url.into(),
+        };
+
+        self.send_authed(self.client.post(endpoint_url).query(&query))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Uploads an image file and sets it as an image of an item.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item.
+    /// * `image_type` - The image to replace.
+    /// * `content_type` - The MIME type of the image, e.g. `"image/jpeg"`.
+    /// * `image` - The content of the image file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn upload_item_image<T: Into<String>>(
+        &self,
+        item_id: T,
+        image_type: ImageType,
+        content_type: T,
+        image: &[u8],
+    ) -> Result<()> {
+        self.upload_item_image_with_progress(item_id, image_type, content_type, image, |_, _| {})
+            .await
+    }
+
+    /// Uploads an image file and sets it as an image of an item, reporting the progress of the
+    /// upload.
+    ///
+    /// The server expects the image encoded in base64, so the sizes given to `progress` are those
+    /// of the encoded body, about a third larger than the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item.
+    /// * `image_type` - The image to replace.
+    /// * `content_type` - The MIME type of the image, e.g. `"image/jpeg"`.
+    /// * `image` - The content of the image file.
+    /// * `progress` - Called with the number of bytes sent so far and the total number of bytes
+    ///   each time a chunk of the body is handed to the connection.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn upload_item_image_with_progress<T, F>(
+        &self,
+        item_id: T,
+        image_type: ImageType,
+        content_type: T,
+        image: &[u8],
+        progress: F,
+    ) -> Result<()>
+    where
+        T: Into<String>,
+        F: Fn(u64, u64) + Send + Sync + 'static,
+    {
+        let endpoint_url = self
+            .url
+            .join(&format!(
+                "/Items/{}/Images/{:?}",
+                item_id.into(),
+                image_type
+            ))
+            .expect("Failed to join URL");
+
+        let body = Bytes::from(STANDARD.encode(image));
+        let total = body.len() as u64;
+
+        let chunks = (0..body.len())
+            .step_by(UPLOAD_CHUNK_SIZE)
+            .map(move |start| {
+                let end = (start + UPLOAD_CHUNK_SIZE).min(body.len());
+                progress(end as u64, total);
+                Ok::<_, Infallible>(body.slice(start..end))
+            });
+
+        let request = self
+            .client
+            .post(endpoint_url)
+            .header(CONTENT_TYPE, content_type.into())
+            .header(CONTENT_LENGTH, total)
+            .body(Body::wrap_stream(stream::iter(chunks)));
+
+        self.send_authed(request).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/images.rs"]
+mod tests;
@@ -1,6 +1,19 @@
+use std::collections::{HashMap, HashSet};
+
+use futures_util::future::try_join;
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
 
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::ids::{ItemId, UserId};
+use crate::playback::{MediaSourceInfo, MediaStreamInfo};
+use crate::serde::comma_separated;
+use crate::serde::pipe_separated;
+use crate::utils::{non_empty_label, patch_json, UserIdQuery};
+use crate::JellyfinClient;
+use url::Url;
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct MediaItem {
@@ -161,8 +174,9 @@ pub struct MediaItem {
 #[serde(rename_all = "PascalCase")]
 pub struct CurrentProgram;
 
+/// A link to the page of an item on an external site, such as IMDb or TheMovieDb.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct ExternalUrl {
     pub name: String,    
     pub url: String
@@ -502,4 +516,1299 @@ pub struct FullNowPlayingItem {
 pub struct NowPlayingQueue {
     pub id: String,    
     pub playlist_item_id: Option<String>
-}
\ No newline at end of file
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct QueryResult<T> {
+    pub items: Vec<T>,
+    pub total_record_count: i64,
+    pub start_index: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BaseItemDto {
+    pub name: Option<String>,
+    pub original_title: Option<String>,
+    pub server_id: Option<String>,
+    pub id: String,
+    pub etag: Option<String>,
+    pub date_created: Option<String>,
+    /// When the metadata of the item was last refreshed. Only returned when
+    /// `ItemFields::DateLastRefreshed` is requested.
+    pub date_last_refreshed: Option<String>,
+    pub container: Option<String>,
+    pub sort_name: Option<String>,
+    /// For people, this is the date of birth.
+    pub premiere_date: Option<String>,
+    /// For people, this is the date of death.
+    pub end_date: Option<String>,
+    pub overview: Option<String>,
+    pub official_rating: Option<String>,
+    pub community_rating: Option<f64>,
+    pub critic_rating: Option<f64>,
+    pub run_time_ticks: Option<i64>,
+    pub production_year: Option<i64>,
+    /// For seasons, the season number, where `0` is the specials season. For books, the number
+    /// of the book in its series.
+    pub index_number: Option<i64>,
+    pub parent_index_number: Option<i64>,
+    pub is_folder: Option<bool>,
+    /// For folders, seasons and series, the number of direct children, e.g. the episodes of a
+    /// season. For genres, studios and people, the number of items tagged with them, when
+    /// `ItemFields::ItemCounts` is requested.
+    pub child_count: Option<i64>,
+    /// For folders, the number of items anywhere below them. Only returned when requested with
+    /// `ItemFields::RecursiveItemCount` or `ItemFields::ItemCounts`.
+    pub recursive_item_count: Option<i64>,
+    pub parent_id: Option<String>,
+    #[serde(rename = "Type")]
+    pub type_: Option<ItemType>,
+    pub media_type: Option<MediaType>,
+    pub collection_type: Option<CollectionType>,
+    /// For episodes, the name of the series. For books, the name of the series the book is part of.
+    pub series_name: Option<String>,
+    pub series_id: Option<String>,
+    pub season_id: Option<String>,
+    pub season_name: Option<String>,
+    pub album: Option<String>,
+    pub album_id: Option<String>,
+    pub album_artist: Option<String>,
+    #[serde(default)]
+    pub artists: Vec<String>,
+    #[serde(default)]
+    pub genres: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The IDs of the item on metadata providers, keyed by provider, e.g. `"Imdb"`. The casing
+    /// of the keys varies between server versions, so prefer `provider_id` to look one up.
+    /// Only returned when `ItemFields::ProviderIds` is requested.
+    #[serde(default)]
+    pub provider_ids: HashMap<String, String>,
+    /// Links to the item on external sites. Only returned when `ItemFields::ExternalUrls` is
+    /// requested.
+    #[serde(default)]
+    pub external_urls: Vec<ExternalUrl>,
+    #[serde(default)]
+    pub image_tags: HashMap<ImageType, String>,
+    #[serde(default)]
+    pub backdrop_image_tags: Vec<String>,
+    /// BlurHash placeholders for the item's images, keyed by image type and then by image tag.
+    #[serde(default)]
+    pub image_blur_hashes: HashMap<ImageType, HashMap<String, String>>,
+    pub primary_image_aspect_ratio: Option<f64>,
+    /// `Virtual` items are placeholders for missing media, such as missing episodes that are only
+    /// returned when the user's `display_missing_episodes` is enabled.
+    pub location_type: Option<LocationType>,
+    /// The requesting user's data for the item, such as the playback position and whether it was
+    /// played. `None` when the item was fetched without a user ID.
+    pub user_data: Option<UserItemData>,
+    /// The playable versions of the item. Only returned when `ItemFields::MediaSources` is requested.
+    #[serde(default)]
+    pub media_sources: Vec<MediaSourceInfo>,
+    /// The streams of the item's default media source. Only returned when
+    /// `ItemFields::MediaStreams` is requested.
+    #[serde(default)]
+    pub media_streams: Vec<MediaStreamInfo>,
+}
+
+impl BaseItemDto {
+    /// Gets the ID of the item on a metadata provider, ignoring the casing of the provider name.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The name of the provider, e.g. `"imdb"`, `"tmdb"` or `"tvdb"`.
+    pub fn provider_id(&self, provider: &str) -> Option<&str> {
+        self.provider_ids
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(provider))
+            .map(|(_, id)| id.as_str())
+    }
+}
+
+/// A user's data for an item, as attached to a `BaseItemDto`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserItemData {
+    pub rating: Option<f64>,
+    /// How much of the item was played, from 0 to 100. Only set while the item is partially played.
+    pub played_percentage: Option<f64>,
+    /// For folders and series, the number of children that were not played yet.
+    pub unplayed_item_count: Option<i64>,
+    #[serde(default)]
+    pub playback_position_ticks: i64,
+    #[serde(default)]
+    pub play_count: i64,
+    #[serde(default)]
+    pub is_favorite: bool,
+    pub likes: Option<bool>,
+    pub last_played_date: Option<String>,
+    #[serde(default)]
+    pub played: bool,
+    pub key: Option<String>,
+    pub item_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ItemType {
+    AggregateFolder,
+    Audio,
+    AudioBook,
+    BasePluginFolder,
+    Book,
+    BoxSet,
+    Channel,
+    ChannelFolderItem,
+    CollectionFolder,
+    Episode,
+    Folder,
+    Genre,
+    ManualPlaylistsFolder,
+    Movie,
+    LiveTvChannel,
+    LiveTvProgram,
+    MusicAlbum,
+    MusicArtist,
+    MusicGenre,
+    MusicVideo,
+    Person,
+    Photo,
+    PhotoAlbum,
+    Playlist,
+    PlaylistsFolder,
+    Program,
+    Recording,
+    Season,
+    Series,
+    Studio,
+    Trailer,
+    TvChannel,
+    TvProgram,
+    UserRootFolder,
+    UserView,
+    Video,
+    Year,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ImageType {
+    #[default]
+    Primary,
+    Art,
+    Backdrop,
+    Banner,
+    Logo,
+    Thumb,
+    Disc,
+    Box,
+    Screenshot,
+    Menu,
+    Chapter,
+    BoxRear,
+    Profile,
+}
+
+/// Optional fields of `BaseItemDto` that the server only fills in when asked for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ItemFields {
+    AirTime,
+    CanDelete,
+    CanDownload,
+    ChannelInfo,
+    Chapters,
+    Trickplay,
+    ChildCount,
+    CumulativeRunTimeTicks,
+    CustomRating,
+    DateCreated,
+    DateLastMediaAdded,
+    DisplayPreferencesId,
+    Etag,
+    ExternalUrls,
+    Genres,
+    ItemCounts,
+    MediaSourceCount,
+    MediaSources,
+    OriginalTitle,
+    Overview,
+    ParentId,
+    Path,
+    People,
+    PlayAccess,
+    ProductionLocations,
+    ProviderIds,
+    PrimaryImageAspectRatio,
+    RecursiveItemCount,
+    Settings,
+    SeriesStudio,
+    SortName,
+    SpecialEpisodeNumbers,
+    Studios,
+    Taglines,
+    Tags,
+    RemoteTrailers,
+    MediaStreams,
+    SeasonUserData,
+    DateLastRefreshed,
+    DateLastSaved,
+    RefreshState,
+    ChannelImage,
+    EnableMediaSourceDisplay,
+    Width,
+    Height,
+    ExtraIds,
+    LocalTrailerCount,
+    #[serde(rename = "IsHD")]
+    IsHd,
+    SpecialFeatureCount,
+}
+
+impl ItemFields {
+    /// The fields a detail page usually shows: the description, credits, external links and the
+    /// media versions and streams to pick from before playing.
+    pub fn detail_preset() -> Vec<Self> {
+        vec![
+            Self::Overview,
+            Self::Taglines,
+            Self::OriginalTitle,
+            Self::Genres,
+            Self::Tags,
+            Self::Studios,
+            Self::People,
+            Self::ProviderIds,
+            Self::ExternalUrls,
+            Self::RemoteTrailers,
+            Self::MediaSources,
+            Self::MediaStreams,
+            Self::Chapters,
+            Self::PrimaryImageAspectRatio,
+        ]
+    }
+
+    /// The fields a grid or list of items usually needs: the aspect ratio to size the posters,
+    /// and the number of children to show on folders and series.
+    ///
+    /// `BasicSyncInfo`, which older clients also request for lists, is left out: Jellyfin 10.9
+    /// removed it, so it is not an `ItemFields` variant.
+    pub fn list_preset() -> Vec<Self> {
+        vec![Self::PrimaryImageAspectRatio, Self::ChildCount]
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LocationType {
+    #[default]
+    FileSystem,
+    Remote,
+    Virtual,
+    Offline,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MediaType {
+    Video,
+    Audio,
+    Photo,
+    Book,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// The role of a person in an item.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PersonType {
+    Actor,
+    Director,
+    Composer,
+    Writer,
+    GuestStar,
+    Producer,
+    Conductor,
+    Lyricist,
+    Arranger,
+    Engineer,
+    Mixer,
+    Remixer,
+    Creator,
+    Artist,
+    AlbumArtist,
+    Author,
+    Illustrator,
+    Penciller,
+    Inker,
+    Colorist,
+    Letterer,
+    CoverArtist,
+    Editor,
+    Translator,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// The kind of content a library or view holds, which decides how clients lay it out.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum CollectionType {
+    Movies,
+    TvShows,
+    Music,
+    MusicVideos,
+    Trailers,
+    HomeVideos,
+    BoxSets,
+    Books,
+    Photos,
+    LiveTv,
+    Playlists,
+    Folders,
+    /// A collection type this crate does not know about, with the value sent by the server.
+    Unknown(String),
+}
+
+impl CollectionType {
+    /// The value the server uses for this collection type, e.g. `"tvshows"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Movies => "movies",
+            Self::TvShows => "tvshows",
+            Self::Music => "music",
+            Self::MusicVideos => "musicvideos",
+            Self::Trailers => "trailers",
+            Self::HomeVideos => "homevideos",
+            Self::BoxSets => "boxsets",
+            Self::Books => "books",
+            Self::Photos => "photos",
+            Self::LiveTv => "livetv",
+            Self::Playlists => "playlists",
+            Self::Folders => "folders",
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<String> for CollectionType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "movies" => Self::Movies,
+            "tvshows" => Self::TvShows,
+            "music" => Self::Music,
+            "musicvideos" => Self::MusicVideos,
+            "trailers" => Self::Trailers,
+            "homevideos" => Self::HomeVideos,
+            "boxsets" => Self::BoxSets,
+            "books" => Self::Books,
+            "photos" => Self::Photos,
+            "livetv" => Self::LiveTv,
+            "playlists" => Self::Playlists,
+            "folders" => Self::Folders,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl From<CollectionType> for String {
+    fn from(value: CollectionType) -> Self {
+        match value {
+            CollectionType::Unknown(value) => value,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SimilarItemsQuery {
+    /// Scopes the query to a user, applying their access rights and including their `UserData`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// Leaves out items by these artists, e.g. the artist of the album the list is shown for.
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub exclude_artist_ids: Vec<String>,
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<ItemFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Drops the items the user has already played. The server does not filter these, so it is
+    /// done on the returned items, which only carry `user_data` when `user_id` is set. Fewer than
+    /// `limit` items may be left, and `total_record_count` stays the one the server reported.
+    #[serde(skip)]
+    pub exclude_watched: bool,
+}
+
+/// The number of items `get_all_items` fetches per request when the query sets no `limit`.
+pub const DEFAULT_PAGE_SIZE: i64 = 500;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ItemsQuery {
+    /// Scopes the query to a user, applying their access rights and including their `UserData`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// Only returns the children of this item, such as the contents of a library, folder or box set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub include_item_types: Vec<ItemType>,
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub exclude_item_types: Vec<ItemType>,
+    /// Only returns items whose name matches this text. Unlike `/Search/Hints`, full items are
+    /// returned, so the search can be combined with the other filters. Ignored when blank.
+    #[serde(skip_serializing_if = "is_blank")]
+    pub search_term: Option<String>,
+    /// Only returns items tagged with any of these genres.
+    #[serde(with = "pipe_separated", skip_serializing_if = "Vec::is_empty")]
+    pub genres: Vec<String>,
+    /// Only returns items rated at least this high by the community, out of 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_community_rating: Option<f64>,
+    /// Only returns items rated at least this high by critics, out of 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_critic_rating: Option<f64>,
+    /// Only returns items with (`true`) or without (`false`) an overview, e.g. to find items
+    /// whose metadata is missing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_overview: Option<bool>,
+    /// Only returns items the user has played, or has not played with `Some(false)`. Requires
+    /// `user_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_played: Option<bool>,
+    /// Only returns items any of these people, looked up with `get_person`, appear in.
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub person_ids: Vec<String>,
+    /// Restricts `person_ids` to these roles, e.g. only the movies a person directed.
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub person_types: Vec<PersonType>,
+    /// Whether to search the whole tree below `parent_id` rather than its direct children only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recursive: Option<bool>,
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub sort_by: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<SortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Additional fields to return, e.g. `ItemFields::list_preset()` for a grid of items.
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<ItemFields>,
+    /// Whether to return the user's data for each item. Requires `user_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_user_data: Option<bool>,
+    /// Whether to return image information at all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_images: Option<bool>,
+    /// The image types to return tags for. `get_items` requests `Primary` when this is empty.
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub enable_image_types: Vec<ImageType>,
+}
+
+fn is_blank(value: &Option<String>) -> bool {
+    match value.as_deref() {
+        Some(value) => value.trim().is_empty(),
+        None => true,
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuggestionsQuery {
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub media_type: Vec<MediaType>,
+    #[serde(
+        rename = "Type",
+        with = "comma_separated",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub type_: Vec<ItemType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Drops the items the user has already played from the suggestions. This is done on the
+    /// returned items, so fewer than `limit` suggestions may be left, and `total_record_count`
+    /// stays the one the server reported.
+    #[serde(skip)]
+    pub exclude_watched: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LatestItemsQuery {
+    /// Only returns items added to this library or folder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub include_item_types: Vec<ItemType>,
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<ItemFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_played: Option<bool>,
+    /// Whether to collapse items into their parent, e.g. episodes into their series. Grouped
+    /// parents are returned with a `child_count` of the items added to them. The server groups
+    /// items unless this is `Some(false)`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_items: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Drops the items returned more than once, keeping the first. Without a `parent_id`, the
+    /// server lists the latest items of each library separately, so a series in two libraries
+    /// can show up twice.
+    #[serde(skip)]
+    pub deduplicate: bool,
+}
+
+/// Filters and paging for `get_play_history`.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct PlayHistoryQuery {
+    /// Only returns items played in this library or folder.
+    pub parent_id: Option<String>,
+    /// Only returns items of these types, e.g. only movies and episodes.
+    pub include_item_types: Vec<ItemType>,
+    pub fields: Vec<ItemFields>,
+    pub start_index: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThemeMediaResult {
+    #[serde(rename = "ThemeSongsResult")]
+    pub theme_songs: QueryResult<BaseItemDto>,
+    #[serde(rename = "ThemeVideosResult")]
+    pub theme_videos: QueryResult<BaseItemDto>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ThemeMediaQuery {
+    user_id: String,
+    inherit_from_parent: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ItemCounts {
+    pub movie_count: i64,
+    pub series_count: i64,
+    pub episode_count: i64,
+    pub artist_count: i64,
+    pub program_count: i64,
+    pub trailer_count: i64,
+    pub song_count: i64,
+    pub album_count: i64,
+    pub music_video_count: i64,
+    pub box_set_count: i64,
+    pub book_count: i64,
+    pub item_count: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ItemCountsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct LikesQuery {
+    likes: bool,
+}
+
+impl JellyfinClient {
+    /// Gets item suggestions for a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user to get suggestions for.
+    /// * `query` - Filters restricting the media types, item types and number of suggestions.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_suggestions<T: Into<String>>(
+        &self,
+        user_id: T,
+        query: &SuggestionsQuery,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        let user_id = user_id.into();
+        let endpoint_url = self
+            .user_scoped_url(
+                &user_id,
+                "/Items/Suggestions",
+                &format!("/Users/{}/Suggestions", user_id),
+            )
+            .await;
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await?;
+        let mut result: QueryResult<BaseItemDto> =
+            response.json().await.map_err(JellyfinError::NetworkError)?;
+
+        if query.exclude_watched {
+            exclude_played(&mut result);
+        }
+
+        Ok(result)
+    }
+
+    /// Gets the items most recently added to the libraries of a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user.
+    /// * `query` - Filters, grouping and fields for the item list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_latest_items<T: Into<String>>(
+        &self,
+        user_id: T,
+        query: &LatestItemsQuery,
+    ) -> Result<Vec<BaseItemDto>> {
+        let user_id = user_id.into();
+        let endpoint_url = self
+            .user_scoped_url(
+                &user_id,
+                "/Items/Latest",
+                &format!("/Users/{}/Items/Latest", user_id),
+            )
+            .await;
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await?;
+        let mut items: Vec<BaseItemDto> =
+            response.json().await.map_err(JellyfinError::NetworkError)?;
+
+        if query.deduplicate {
+            let mut seen = HashSet::new();
+            items.retain(|item| seen.insert(item.id.clone()));
+        }
+
+        Ok(items)
+    }
+
+    /// Gets the items a user has finished playing, the most recently played first, for a
+    /// watch history.
+    ///
+    /// Unlike resumable items, only items played to the end are returned. Each item carries its
+    /// `user_data`, whose `last_played_date` tells when it was played.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user.
+    /// * `query` - Filters, fields and paging for the history.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_play_history<T: Into<String>>(
+        &self,
+        user_id: T,
+        query: &PlayHistoryQuery,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        self.get_items(&ItemsQuery {
+            user_id: Some(user_id.into()),
+            parent_id: query.parent_id.clone(),
+            include_item_types: query.include_item_types.clone(),
+            is_played: Some(true),
+            recursive: Some(true),
+            sort_by: vec!["DatePlayed".to_string()],
+            sort_order: Some(SortOrder::Descending),
+            start_index: query.start_index,
+            limit: query.limit,
+            fields: query.fields.clone(),
+            enable_user_data: Some(true),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Gets items similar to an item, for a "more like this" list.
+    ///
+    /// Works for any kind of item, including music: for an album, similar albums are returned,
+    /// and for an artist, similar artists.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item to find similar items for.
+    /// * `query` - The user, exclusions and fields for the item list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_similar_items<T: Into<String>>(
+        &self,
+        item_id: T,
+        query: &SimilarItemsQuery,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/{}/Similar", item_id.into()))
+            .expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await?;
+        let mut result: QueryResult<BaseItemDto> =
+            response.json().await.map_err(JellyfinError::NetworkError)?;
+
+        if query.exclude_watched {
+            exclude_played(&mut result);
+        }
+
+        Ok(result)
+    }
+
+    /// Gets the special features (extras) of an item, such as trailers and behind-the-scenes clips.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user requesting the special features.
+    /// * `item_id` - The ID of the item whose special features are fetched.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_special_features<T: Into<String>>(
+        &self,
+        user_id: T,
+        item_id: T,
+    ) -> Result<Vec<BaseItemDto>> {
+        let (user_id, item_id) = (user_id.into(), item_id.into());
+        let endpoint_url = self
+            .user_scoped_url(
+                &user_id,
+                &format!("/Items/{}/SpecialFeatures", item_id),
+                &format!("/Users/{}/Items/{}/SpecialFeatures", user_id, item_id),
+            )
+            .await;
+
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets the intros (pre-roll videos) to play before an item.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user requesting the intros.
+    /// * `item_id` - The ID of the item that is about to be played.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_intros<T: Into<String>>(
+        &self,
+        user_id: T,
+        item_id: T,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        let (user_id, item_id) = (user_id.into(), item_id.into());
+        let endpoint_url = self
+            .user_scoped_url(
+                &user_id,
+                &format!("/Items/{}/Intros", item_id),
+                &format!("/Users/{}/Items/{}/Intros", user_id, item_id),
+            )
+            .await;
+
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets the theme songs and theme videos of an item.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user requesting the theme media.
+    /// * `item_id` - The ID of the item whose theme media is fetched.
+    /// * `inherit_from_parent` - Whether to fall back to the theme media of the item's parents,
+    ///   e.g. a series' theme song when fetching an episode.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `ThemeMediaResult` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_theme_media<T: Into<String>>(
+        &self,
+        user_id: T,
+        item_id: T,
+        inherit_from_parent: bool,
+    ) -> Result<ThemeMediaResult> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/{}/ThemeMedia", item_id.into()))
+            .expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(&ThemeMediaQuery {
+                user_id: user_id.into(),
+                inherit_from_parent,
+            }))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets the number of items of each type in the library.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - Optionally restricts the counts to the items the given user has access to.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `ItemCounts` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_item_counts(&self, user_id: Option<String>) -> Result<ItemCounts> {
+        let endpoint_url = self.url.join("/Items/Counts").expect("Failed to join URL");
+
+        let response = self
+            .send_authed(
+                self.client
+                    .get(endpoint_url)
+                    .query(&ItemCountsQuery { user_id }),
+            )
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets the items matching a query.
+    ///
+    /// Unless images are disabled with `enable_images`, the tags of the `Primary` images are
+    /// requested when `enable_image_types` is empty, so thumbnails can be built from the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The filters, sorting and paging to apply.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_items(&self, query: &ItemsQuery) -> Result<QueryResult<BaseItemDto>> {
+        let endpoint_url = self.url.join("/Items").expect("Failed to join URL");
+
+        let mut request = self.client.get(endpoint_url).query(query);
+        if query.enable_image_types.is_empty() && query.enable_images != Some(false) {
+            request = request.query(&[("EnableImageTypes", ImageType::Primary)]);
+        }
+
+        let response = self.send_authed(request).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets every item matching a query, fetching it page by page.
+    ///
+    /// All the items are held in memory at once, so this is meant for moderate result sets such
+    /// as a single library of a home server. The number of matching items is checked against
+    /// `max_items` on the first page, and nothing more is fetched when it is exceeded, which keeps
+    /// a mistaken query from pulling a whole large library.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The filters and sorting to apply. Paging starts at `start_index`, and `limit`
+    ///   sets the page size, defaulting to `DEFAULT_PAGE_SIZE`.
+    /// * `max_items` - The most items to fetch.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `BaseItemDto` instances if successful, `JellyfinError::TooManyItems` if more than `max_items` items match, or another `JellyfinError` otherwise.
+    pub async fn get_all_items(
+        &self,
+        query: &ItemsQuery,
+        max_items: usize,
+    ) -> Result<Vec<BaseItemDto>> {
+        let start_index = query.start_index.unwrap_or(0);
+        let mut page_query = ItemsQuery {
+            limit: Some(query.limit.unwrap_or(DEFAULT_PAGE_SIZE)),
+            ..query.clone()
+        };
+        let mut items = Vec::new();
+
+        loop {
+            page_query.start_index = Some(start_index + items.len() as i64);
+            let page = self.get_items(&page_query).await?;
+
+            let remaining = usize::try_from(page.total_record_count - start_index).unwrap_or(0);
+            if remaining > max_items {
+                return Err(JellyfinError::TooManyItems {
+                    matched: remaining,
+                    max: max_items,
+                });
+            }
+
+            let fetched = page.items.len();
+            items.extend(page.items);
+            if fetched == 0 || items.len() >= remaining {
+                return Ok(items);
+            }
+        }
+    }
+
+    /// Gets the items contained in a collection (box set).
+    ///
+    /// Box sets are regular items whose members are their children, so this is equivalent to
+    /// calling `get_items` with `parent_id` set to the collection's ID (`/Items?parentId=...`).
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_id` - The ID of the collection.
+    /// * `user_id` - The ID of the user browsing the collection.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_collection_items<T: Into<String>>(
+        &self,
+        collection_id: T,
+        user_id: T,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        self.get_items(&ItemsQuery {
+            user_id: Some(user_id.into()),
+            parent_id: Some(collection_id.into()),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Gets a single item, with all of its metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user requesting the item.
+    /// * `item_id` - The ID of the item.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `BaseItemDto` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_item<U: Into<UserId>, I: Into<ItemId>>(
+        &self,
+        user_id: U,
+        item_id: I,
+    ) -> Result<BaseItemDto> {
+        let (user_id, item_id) = (user_id.into(), item_id.into());
+        let endpoint_url = self.item_url(user_id.as_str(), item_id.as_str()).await;
+
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets the folders an item is nested in, such as the library, series and season of an
+    /// episode, to build a breadcrumb trail.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item.
+    /// * `user_id` - The ID of the user requesting the ancestors.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `BaseItemDto` instances ordered from the root down to the item's parent if successful, or a `JellyfinError` otherwise.
+    pub async fn get_item_ancestors<T: Into<String>>(
+        &self,
+        item_id: T,
+        user_id: T,
+    ) -> Result<Vec<BaseItemDto>> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/{}/Ancestors", item_id.into()))
+            .expect("Failed to join URL");
+
+        let query = UserIdQuery {
+            user_id: user_id.into(),
+        };
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(&query))
+            .await?;
+
+        // The server lists the nearest ancestor first.
+        let mut ancestors: Vec<BaseItemDto> =
+            response.json().await.map_err(JellyfinError::NetworkError)?;
+        ancestors.reverse();
+
+        Ok(ancestors)
+    }
+
+    /// Updates the metadata of an item using a closure, so only the fields that need to change
+    /// have to be touched. Requires an administrator.
+    ///
+    /// The item is fetched, patched and sent back. Fields that `BaseItemDto` does not model are
+    /// sent back exactly as the server returned them, so they are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user used to read the item.
+    /// * `item_id` - The ID of the item to update.
+    /// * `patch` - A closure modifying the current metadata.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the updated `BaseItemDto` if successful, or a `JellyfinError` otherwise.
+    pub async fn update_item_metadata<T: Into<String>, F: FnOnce(&mut BaseItemDto)>(
+        &self,
+        user_id: T,
+        item_id: T,
+        patch: F,
+    ) -> Result<BaseItemDto> {
+        let item_id = item_id.into();
+        let mut raw = self.get_item_json(user_id.into(), item_id.clone()).await?;
+
+        let item: BaseItemDto = patch_json(&mut raw, patch)?;
+
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/{}", item_id))
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.post(endpoint_url).json(&raw))
+            .await?;
+
+        Ok(item)
+    }
+
+    /// Adds a tag to an item, if it does not have it yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user used to read the item.
+    /// * `item_id` - The ID of the item.
+    /// * `tag` - The tag to add. Must not be empty.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the updated `BaseItemDto` if successful, `JellyfinError::InvalidInput` if the tag is empty, or another `JellyfinError` otherwise.
+    pub async fn add_item_tag<T: Into<String>>(
+        &self,
+        user_id: T,
+        item_id: T,
+        tag: T,
+    ) -> Result<BaseItemDto> {
+        let tag = non_empty_label("Tag", tag.into())?;
+
+        self.update_item_metadata(user_id, item_id, |item| {
+            if !item.tags.contains(&tag) {
+                item.tags.push(tag);
+            }
+        })
+        .await
+    }
+
+    /// Removes a tag from an item.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user used to read the item.
+    /// * `item_id` - The ID of the item.
+    /// * `tag` - The tag to remove. Must not be empty.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the updated `BaseItemDto` if successful, `JellyfinError::InvalidInput` if the tag is empty, or another `JellyfinError` otherwise.
+    pub async fn remove_item_tag<T: Into<String>>(
+        &self,
+        user_id: T,
+        item_id: T,
+        tag: T,
+    ) -> Result<BaseItemDto> {
+        let tag = non_empty_label("Tag", tag.into())?;
+
+        self.update_item_metadata(user_id, item_id, |item| {
+            item.tags.retain(|existing| *existing != tag)
+        })
+        .await
+    }
+
+    /// Adds a genre to an item, if it does not have it yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user used to read the item.
+    /// * `item_id` - The ID of the item.
+    /// * `genre` - The genre to add. Must not be empty.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the updated `BaseItemDto` if successful, `JellyfinError::InvalidInput` if the genre is empty, or another `JellyfinError` otherwise.
+    pub async fn add_item_genre<T: Into<String>>(
+        &self,
+        user_id: T,
+        item_id: T,
+        genre: T,
+    ) -> Result<BaseItemDto> {
+        let genre = non_empty_label("Genre", genre.into())?;
+
+        self.update_item_metadata(user_id, item_id, |item| {
+            if !item.genres.contains(&genre) {
+                item.genres.push(genre);
+            }
+        })
+        .await
+    }
+
+    /// Removes a genre from an item.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user used to read the item.
+    /// * `item_id` - The ID of the item.
+    /// * `genre` - The genre to remove. Must not be empty.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the updated `BaseItemDto` if successful, `JellyfinError::InvalidInput` if the genre is empty, or another `JellyfinError` otherwise.
+    pub async fn remove_item_genre<T: Into<String>>(
+        &self,
+        user_id: T,
+        item_id: T,
+        genre: T,
+    ) -> Result<BaseItemDto> {
+        let genre = non_empty_label("Genre", genre.into())?;
+
+        self.update_item_metadata(user_id, item_id, |item| {
+            item.genres.retain(|existing| *existing != genre)
+        })
+        .await
+    }
+
+    /// Fetches an item as raw JSON, keeping the fields `BaseItemDto` does not model. The cache is
+    /// bypassed, so a patch is never applied to stale metadata.
+    async fn get_item_json(&self, user_id: String, item_id: String) -> Result<serde_json::Value> {
+        let endpoint_url = self.item_url(&user_id, &item_id).await;
+
+        let response = self
+            .send_authed_uncached(self.client.get(endpoint_url))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// The URL of a single item, as seen by a user.
+    async fn item_url(&self, user_id: &str, item_id: &str) -> Url {
+        self.user_scoped_url(
+            user_id,
+            &format!("/Items/{}", item_id),
+            &format!("/Users/{}/Items/{}", user_id, item_id),
+        )
+        .await
+    }
+
+    /// Gets the views, i.e. the libraries and other top-level folders, shown to a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_user_views<T: Into<String>>(
+        &self,
+        user_id: T,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        let user_id = user_id.into();
+        let endpoint_url = self
+            .user_scoped_url(&user_id, "/UserViews", &format!("/Users/{}/Views", user_id))
+            .await;
+
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets the views shown to a user, in the order the user arranged them in.
+    ///
+    /// The views are sorted by the `ordered_views` of the user's configuration, as the web client
+    /// does for its home screen. Views the user has not arranged come last.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_ordered_user_views<T: Into<String>>(
+        &self,
+        user_id: T,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        let user_id = user_id.into();
+
+        let (mut views, user) = try_join(
+            self.get_user_views(user_id.as_str()),
+            self.get_user_by_id(user_id.as_str()),
+        )
+        .await?;
+        user.configuration.sort_views(&mut views.items);
+
+        Ok(views)
+    }
+
+    /// Likes or dislikes an item for a user, or clears the rating.
+    ///
+    /// Likes are separate from favorites and are used by some recommendation features.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user rating the item.
+    /// * `item_id` - The ID of the item to rate.
+    /// * `likes` - `Some(true)` to like the item, `Some(false)` to dislike it, or `None` to clear the rating.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the user's updated `UserItemData` if successful, or a `JellyfinError` otherwise.
+    pub async fn set_item_likes<T: Into<String>>(
+        &self,
+        user_id: T,
+        item_id: T,
+        likes: Option<bool>,
+    ) -> Result<UserItemData> {
+        let (user_id, item_id) = (user_id.into(), item_id.into());
+        let endpoint_url = self
+            .user_scoped_url(
+                &user_id,
+                &format!("/UserItems/{}/Rating", item_id),
+                &format!("/Users/{}/Items/{}/Rating", user_id, item_id),
+            )
+            .await;
+
+        let request = match likes {
+            Some(likes) => self.client.post(endpoint_url).query(&LikesQuery { likes }),
+            None => self.client.delete(endpoint_url),
+        };
+
+        let response = self.send_authed(request).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+}
+
+/// Drops the played items from a result. Items without `user_data` are kept, as whether they were
+/// played is unknown.
+/// Drops the played items of a page. `total_record_count` is left as the server reported it: the
+/// played items of the other pages are unknown, so it cannot be corrected.
+fn exclude_played(result: &mut QueryResult<BaseItemDto>) {
+    result
+        .items
+        .retain(|item| !item.user_data.as_ref().is_some_and(|data| data.played));
+}
+
+#[cfg(test)]
+#[path = "tests/items.rs"]
+mod tests;
@@ -0,0 +1,252 @@
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use serde_json::{Map, Value};
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::CollectionType;
+use crate::utils::{forbidden_as, non_empty_label};
+use crate::JellyfinClient;
+
+const LIBRARY_FORBIDDEN: &str = "Managing libraries requires an administrator";
+
+/// A library of the server, as listed in the dashboard.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct VirtualFolderInfo {
+    pub name: String,
+    /// The folders the library is made of, as paths on the server.
+    pub locations: Vec<String>,
+    /// The kind of content of the library. `None` for libraries of mixed content.
+    pub collection_type: Option<CollectionType>,
+    /// The ID of the library's folder item, to browse it with `ItemsQuery::parent_id`.
+    pub item_id: Option<String>,
+    pub library_options: Option<LibraryOptions>,
+    /// `"Active"` while the library is being scanned, `"Idle"` otherwise.
+    pub refresh_status: Option<String>,
+}
+
+/// The settings of a library.
+///
+/// Only the most common settings are modeled; the others are kept in `other`, so options read
+/// from the server can be changed and sent back without losing any.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct LibraryOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Whether to pick up file changes as they happen rather than on the next scan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_realtime_monitor: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_photos: Option<bool>,
+    /// Whether to save the metadata and images next to the media files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_local_metadata: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_chapter_image_extraction: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_trickplay_image_extraction: Option<bool>,
+    /// The language to fetch metadata in, e.g. `"en"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_metadata_language: Option<String>,
+    /// The country to fetch ratings for, e.g. `"US"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_country_code: Option<String>,
+    /// The folders of the library.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub path_infos: Vec<MediaPathInfo>,
+    /// The settings not modeled above, as sent by the server.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// A folder of a library.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct MediaPathInfo {
+    /// The path of the folder on the server.
+    pub path: String,
+}
+
+/// A library to create with `add_virtual_folder`.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AddLibraryRequest {
+    pub name: String,
+    /// The kind of content of the library, or `None` for mixed content.
+    pub collection_type: Option<CollectionType>,
+    /// The folders the library is made of, as paths on the server, e.g. `"/media/movies"`.
+    pub paths: Vec<String>,
+    pub library_options: LibraryOptions,
+    /// Whether to scan the library right after creating it.
+    pub refresh_library: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct AddVirtualFolderQuery<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collection_type: Option<&'a CollectionType>,
+    refresh_library: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct AddVirtualFolderBody<'a> {
+    library_options: &'a LibraryOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct VirtualFolderNameQuery {
+    name: String,
+}
+
+/// A folder of a library, sent as the body when adding it and as the query when removing it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct MediaPath {
+    name: String,
+    path: String,
+}
+
+impl JellyfinClient {
+    /// Lists the libraries of the server. Requires an administrator.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `VirtualFolderInfo` instances if successful, `JellyfinError::Forbidden` if the user is not an administrator, or another `JellyfinError` otherwise.
+    pub async fn get_virtual_folders(&self) -> Result<Vec<VirtualFolderInfo>> {
+        let endpoint_url = self
+            .url
+            .join("/Library/VirtualFolders")
+            .expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url))
+            .await
+            .map_err(|err| forbidden_as(err, LIBRARY_FORBIDDEN))?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Creates a library. Requires an administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The name, content type, folders and settings of the library.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, `JellyfinError::Forbidden` if the user is not an administrator, or another `JellyfinError` otherwise.
+    pub async fn add_virtual_folder(&self, request: &AddLibraryRequest) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join("/Library/VirtualFolders")
+            .expect("Failed to join URL");
+
+        let query = AddVirtualFolderQuery {
+            name: &request.name,
+            collection_type: request.collection_type.as_ref(),
+            refresh_library: request.refresh_library,
+        };
+        let body = AddVirtualFolderBody {
+            library_options: &request.library_options,
+        };
+
+        let mut builder = self.client.post(endpoint_url).query(&query);
+        for path in &request.paths {
+            builder = builder.query(&[("Paths", path)]);
+        }
+
+        self.send_authed(builder.json(&body))
+            .await
+            .map_err(|err| forbidden_as(err, LIBRARY_FORBIDDEN))?;
+
+        Ok(())
+    }
+
+    /// Deletes a library. The media files are left untouched. Requires an administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the library.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, `JellyfinError::Forbidden` if the user is not an administrator, or another `JellyfinError` otherwise.
+    pub async fn remove_virtual_folder<T: Into<String>>(&self, name: T) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join("/Library/VirtualFolders")
+            .expect("Failed to join URL");
+
+        let query = VirtualFolderNameQuery { name: name.into() };
+
+        self.send_authed(self.client.delete(endpoint_url).query(&query))
+            .await
+            .map_err(|err| forbidden_as(err, LIBRARY_FORBIDDEN))?;
+
+        Ok(())
+    }
+
+    /// Adds a folder to a library, e.g. one on another drive. Requires an administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the library.
+    /// * `path` - The path of the folder on the server.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, `JellyfinError::InvalidInput` if the name or path is empty, `JellyfinError::Forbidden` if the user is not an administrator, or another `JellyfinError` otherwise.
+    pub async fn add_media_path<T: Into<String>>(&self, name: T, path: T) -> Result<()> {
+        let media_path = MediaPath {
+            name: non_empty_label("Library name", name.into())?,
+            path: non_empty_label("Path", path.into())?,
+        };
+        let endpoint_url = self
+            .url
+            .join("/Library/VirtualFolders/Paths")
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.post(endpoint_url).json(&media_path))
+            .await
+            .map_err(|err| forbidden_as(err, LIBRARY_FORBIDDEN))?;
+
+        Ok(())
+    }
+
+    /// Removes a folder from a library. The media files are left untouched. Requires an
+    /// administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the library.
+    /// * `path` - The path of the folder on the server, as listed in `VirtualFolderInfo::locations`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, `JellyfinError::InvalidInput` if the name or path is empty, `JellyfinError::Forbidden` if the user is not an administrator, or another `JellyfinError` otherwise.
+    pub async fn remove_media_path<T: Into<String>>(&self, name: T, path: T) -> Result<()> {
+        let media_path = MediaPath {
+            name: non_empty_label("Library name", name.into())?,
+            path: non_empty_label("Path", path.into())?,
+        };
+        let endpoint_url = self
+            .url
+            .join("/Library/VirtualFolders/Paths")
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.delete(endpoint_url).query(&media_path))
+            .await
+            .map_err(|err| forbidden_as(err, LIBRARY_FORBIDDEN))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/library.rs"]
+mod tests;
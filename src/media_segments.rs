@@ -0,0 +1,89 @@
+use reqwest::StatusCode;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::QueryResult;
+use crate::serde::comma_separated;
+use crate::JellyfinClient;
+
+/// What a media segment of an item contains.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MediaSegmentType {
+    #[default]
+    Unknown,
+    Commercial,
+    /// A preview of upcoming content, e.g. the next episode.
+    Preview,
+    /// A recap of previous content, e.g. the previous episodes.
+    Recap,
+    /// The end credits.
+    Outro,
+    /// The opening sequence.
+    Intro,
+}
+
+/// A marked section of an item, such as an intro that can be skipped.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaSegment {
+    pub id: String,
+    pub item_id: String,
+    #[serde(rename = "Type")]
+    pub segment_type: MediaSegmentType,
+    pub start_ticks: i64,
+    pub end_ticks: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaSegmentQuery {
+    /// Only returns segments of these types, or all segments when empty.
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub include_segment_types: Vec<MediaSegmentType>,
+}
+
+impl JellyfinClient {
+    /// Gets the media segments of an item, such as its intro and credits, to offer skipping them.
+    ///
+    /// Media segments are available from Jellyfin 10.10. Servers that do not provide them, or that
+    /// have them disabled, answer with `404 Not Found`, which is returned as an empty result.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item.
+    /// * `query` - Filters for the segment list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `MediaSegment` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_media_segments<T: Into<String>>(
+        &self,
+        item_id: T,
+        query: &MediaSegmentQuery,
+    ) -> Result<QueryResult<MediaSegment>> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/MediaSegments/{}", item_id.into()))
+            .expect("Failed to join URL");
+
+        let response = match self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await
+        {
+            Err(JellyfinError::HttpRequestError { status, .. })
+                if status == StatusCode::NOT_FOUND.as_u16() =>
+            {
+                return Ok(QueryResult::default());
+            }
+            response => response?,
+        };
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/media_segments.rs"]
+mod tests;
@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+use std::io;
+use std::time::Duration;
+
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use tokio::time::Instant;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::{BaseItemDto, ItemFields, QueryResult};
+use crate::serde::comma_separated;
+use crate::JellyfinClient;
+
+/// How much of an item's metadata or images a refresh replaces.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MetadataRefreshMode {
+    /// Leaves the metadata as it is.
+    None,
+    /// Only checks that the files of the item still exist.
+    ValidationOnly,
+    /// Fills in what is missing, like the scheduled library scan.
+    #[default]
+    Default,
+    /// Fetches everything again from the metadata providers.
+    FullRefresh,
+}
+
+/// What `refresh_item` refreshes. The default refreshes missing metadata and images only.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RefreshOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_refresh_mode: Option<MetadataRefreshMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_refresh_mode: Option<MetadataRefreshMode>,
+    /// Whether to discard the existing metadata, including manual edits, with `FullRefresh`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replace_all_metadata: Option<bool>,
+    /// Whether to discard the existing images with `FullRefresh`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replace_all_images: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regenerate_trickplay: Option<bool>,
+}
+
+/// What to look for on the metadata providers with `remote_search_movie`, `remote_search_series`
+/// or `remote_search_episode`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RemoteSearchQuery {
+    pub search_info: RemoteSearchInfo,
+    /// The item being identified, so its current metadata helps the search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<String>,
+    /// Only searches this provider, e.g. `"TheMovieDb"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_provider_name: Option<String>,
+    /// Also searches the providers disabled for the item's library.
+    pub include_disabled_providers: bool,
+}
+
+/// The known details of the item to identify. Any combination can be given.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RemoteSearchInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<i64>,
+    /// IDs already known on metadata providers, keyed by provider, e.g. `"Imdb"`.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub provider_ids: HashMap<String, String>,
+    /// For episodes, the episode number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_number: Option<i64>,
+    /// For episodes, the season number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_index_number: Option<i64>,
+}
+
+/// A match found on a metadata provider, to apply with `apply_remote_search_result`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct RemoteSearchResult {
+    pub name: Option<String>,
+    pub production_year: Option<i64>,
+    pub provider_ids: HashMap<String, String>,
+    pub image_url: Option<String>,
+    pub search_provider_name: Option<String>,
+    pub overview: Option<String>,
+    pub premiere_date: Option<String>,
+    pub index_number: Option<i64>,
+    pub parent_index_number: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApplyRemoteSearchQuery {
+    replace_all_images: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct RefreshedItemQuery {
+    ids: String,
+    #[serde(with = "comma_separated")]
+    fields: Vec<ItemFields>,
+}
+
+impl JellyfinClient {
+    /// Queues a refresh of the metadata and images of an item. Requires an administrator.
+    ///
+    /// The server refreshes the item in the background; `refresh_item_and_wait` waits for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item to refresh. Folders are refreshed recursively.
+    /// * `options` - What to refresh.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn refresh_item<T: Into<String>>(
+        &self,
+        item_id: T,
+        options: &RefreshOptions,
+    ) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/{}/Refresh", item_id.into()))
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.post(endpoint_url).query(options))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Refreshes an item and waits until the server is done with it, by polling the item until
+    /// its `date_last_refreshed` changes. Requires an administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item to refresh.
+    /// * `options` - What to refresh.
+    /// * `poll_interval` - The time between two checks of the item.
+    /// * `timeout` - The longest time to wait for the refresh to finish.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the refreshed `BaseItemDto` if successful, `JellyfinError::IoError` with
+    /// `TimedOut` if the refresh did not finish within `timeout`, `JellyfinError::NotFound` if the
+    /// item does not exist, or another `JellyfinError` otherwise.
+    pub async fn refresh_item_and_wait<T: Into<String>>(
+        &self,
+        item_id: T,
+        options: &RefreshOptions,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<BaseItemDto> {
+        let item_id = item_id.into();
+        let deadline = Instant::now() + timeout;
+
+        let before = self.get_refreshed_item(&item_id).await?.date_last_refreshed;
+        self.refresh_item(item_id.as_str(), options).await?;
+
+        loop {
+            if Instant::now() + poll_interval > deadline {
+                return Err(JellyfinError::IoError(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("The refresh of item {} did not finish in time", item_id),
+                )));
+            }
+            tokio::time::sleep(poll_interval).await;
+
+            let item = self.get_refreshed_item(&item_id).await?;
+            if item.date_last_refreshed.is_some() && item.date_last_refreshed != before {
+                return Ok(item);
+            }
+        }
+    }
+
+    /// Searches the metadata providers for movies, to fix a wrong match. Requires an
+    /// administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The known details of the movie.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `RemoteSearchResult` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn remote_search_movie(
+        &self,
+        query: &RemoteSearchQuery,
+    ) -> Result<Vec<RemoteSearchResult>> {
+        self.remote_search("Movie", query).await
+    }
+
+    /// Searches the metadata providers for series, to fix a wrong match. Requires an
+    /// administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The known details of the series.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `RemoteSearchResult` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn remote_search_series(
+        &self,
+        query: &RemoteSearchQuery,
+    ) -> Result<Vec<RemoteSearchResult>> {
+        self.remote_search("Series", query).await
+    }
+
+    /// Searches the metadata providers for episodes, to fix a wrong match. Requires an
+    /// administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The known details of the episode, usually with its season and episode numbers.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `RemoteSearchResult` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn remote_search_episode(
+        &self,
+        query: &RemoteSearchQuery,
+    ) -> Result<Vec<RemoteSearchResult>> {
+        self.remote_search("Episode", query).await
+    }
+
+    async fn remote_search(
+        &self,
+        kind: &str,
+        query: &RemoteSearchQuery,
+    ) -> Result<Vec<RemoteSearchResult>> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/RemoteSearch/{}", kind))
+            .expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.post(endpoint_url).json(query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Identifies an item as a match found with a remote search, replacing its metadata with the
+    /// match's. Requires an administrator.
+    ///
+    /// The server refreshes the item in the background afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item to identify.
+    /// * `result` - The match, as returned by a remote search.
+    /// * `replace_all_images` - Whether to also replace the images of the item.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn apply_remote_search_result<T: Into<String>>(
+        &self,
+        item_id: T,
+        result: &RemoteSearchResult,
+        replace_all_images: bool,
+    ) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/RemoteSearch/Apply/{}", item_id.into()))
+            .expect("Failed to join URL");
+
+        let query = ApplyRemoteSearchQuery { replace_all_images };
+
+        self.send_authed(self.client.post(endpoint_url).query(&query).json(result))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Gets an item with the metadata a refresh updates, bypassing the cache.
+    async fn get_refreshed_item(&self, item_id: &str) -> Result<BaseItemDto> {
+        let endpoint_url = self.url.join("/Items").expect("Failed to join URL");
+
+        let query = RefreshedItemQuery {
+            ids: item_id.to_string(),
+            fields: vec![
+                ItemFields::DateLastRefreshed,
+                ItemFields::Overview,
+                ItemFields::Genres,
+                ItemFields::Tags,
+                ItemFields::ProviderIds,
+                ItemFields::ExternalUrls,
+            ],
+        };
+
+        let response = self
+            .send_authed_uncached(self.client.get(endpoint_url).query(&query))
+            .await?;
+
+        let result: QueryResult<BaseItemDto> =
+            response.json().await.map_err(JellyfinError::NetworkError)?;
+
+        result
+            .items
+            .into_iter()
+            .next()
+            .ok_or_else(|| JellyfinError::not_found(format!("Item {}", item_id)))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/metadata.rs"]
+mod tests;
@@ -0,0 +1,250 @@
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use reqwest::StatusCode;
+use url::Url;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::{BaseItemDto, ItemType, ItemsQuery, QueryResult};
+use crate::serde::comma_separated;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ArtistsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// Only returns artists from this library or folder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+/// How a transcoded stream is delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TranscodingProtocol {
+    /// A single progressive HTTP stream.
+    Http,
+    /// An HLS playlist of segments, which adapts better to unreliable connections.
+    Hls,
+}
+
+/// The stream parameters of `audio_transcode_url`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AudioTranscodeParams {
+    /// The containers the client can play as is, e.g. `mp3` or `flac`. When any are given, the
+    /// server plays matching files directly and only transcodes the others.
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub container: Vec<String>,
+    /// The codec to transcode to, e.g. `"mp3"` or `"aac"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_codec: Option<String>,
+    /// The maximum bitrate of the stream, in bits per second.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_streaming_bitrate: Option<i64>,
+    /// The container to transcode to, e.g. `"mp3"` or `"ts"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcoding_container: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcoding_protocol: Option<TranscodingProtocol>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// The device the transcoding session belongs to. Defaults to the client's device ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_source_id: Option<String>,
+    /// The ID to report playback with, from `get_playback_info`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_session_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_ticks: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LyricResponse {
+    pub metadata: LyricMetadata,
+    pub lyrics: Vec<LyricLine>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LyricMetadata {
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub length: Option<i64>,
+    pub by: Option<String>,
+    pub offset: Option<i64>,
+    pub creator: Option<String>,
+    pub version: Option<String>,
+    pub is_synced: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LyricLine {
+    pub text: String,
+    /// The time the line starts at, in ticks. Only set for synced lyrics.
+    pub start: Option<i64>,
+}
+
+impl JellyfinClient {
+    /// Gets all artists, including the artists of individual tracks on compilations.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Filters and paging for the artist list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_artists(&self, query: &ArtistsQuery) -> Result<QueryResult<BaseItemDto>> {
+        let endpoint_url = self.url.join("/Artists").expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets the album artists.
+    ///
+    /// Unlike `get_artists`, this only returns the artists albums are credited to, so a
+    /// compilation is grouped under its album artist (such as "Various Artists") rather than under
+    /// each of its track artists.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Filters and paging for the artist list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_album_artists(
+        &self,
+        query: &ArtistsQuery,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        let endpoint_url = self
+            .url
+            .join("/Artists/AlbumArtists")
+            .expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets music albums.
+    ///
+    /// This is `get_items` restricted to `MusicAlbum` items, searching recursively unless the query
+    /// says otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Filters, sorting and paging for the album list. Its item types are ignored.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_albums(&self, query: &ItemsQuery) -> Result<QueryResult<BaseItemDto>> {
+        self.get_items(&ItemsQuery {
+            include_item_types: vec![ItemType::MusicAlbum],
+            recursive: query.recursive.or(Some(true)),
+            ..query.clone()
+        })
+        .await
+    }
+
+    /// Builds the URL a media player can stream an audio item from, transcoded to a format the
+    /// player supports.
+    ///
+    /// With accepted `container`s, the `/Audio/{id}/universal` endpoint the web client uses is
+    /// targeted, which only transcodes files the player cannot play as is. Otherwise, the item is
+    /// always transcoded, to a file named after the `transcoding_container` (or the `audio_codec`,
+    /// or MP3 by default). The URL carries the current access token, so the player does not need
+    /// to send any headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the audio item.
+    /// * `params` - The formats the player supports and the format to transcode to.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the stream `Url` if successful, or `JellyfinError::AuthNotFound` if the client is not authenticated.
+    pub fn audio_transcode_url<T: Into<String>>(
+        &self,
+        item_id: T,
+        params: &AudioTranscodeParams,
+    ) -> Result<Url> {
+        let item_id = item_id.into();
+        let params = AudioTranscodeParams {
+            device_id: params
+                .device_id
+                .clone()
+                .or_else(|| Some(self.client_info().device_id.clone())),
+            ..params.clone()
+        };
+
+        let path = if params.container.is_empty() {
+            let extension = params
+                .transcoding_container
+                .as_deref()
+                .or(params.audio_codec.as_deref())
+                .unwrap_or("mp3");
+            format!("/Audio/{}/stream.{}", item_id, extension)
+        } else {
+            format!("/Audio/{}/universal", item_id)
+        };
+
+        self.authed_url(&path, &params)
+    }
+
+    /// Gets the lyrics of an audio item.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the audio item.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `LyricResponse` if successful, `JellyfinError::NotFound` if the track
+    /// has no lyrics, or another `JellyfinError` otherwise.
+    pub async fn get_lyrics<T: Into<String>>(&self, item_id: T) -> Result<LyricResponse> {
+        let item_id = item_id.into();
+        let endpoint_url = self
+            .url
+            .join(&format!("/Audio/{}/Lyrics", item_id))
+            .expect("Failed to join URL");
+
+        let response = match self.send_authed(self.client.get(endpoint_url)).await {
+            Err(JellyfinError::HttpRequestError { status, .. })
+                if status == StatusCode::NOT_FOUND.as_u16() =>
+            {
+                return Err(JellyfinError::not_found(format!(
+                    "No lyrics for item {}",
+                    item_id
+                )));
+            }
+            response => response?,
+        };
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/music.rs"]
+mod tests;
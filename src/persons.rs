@@ -0,0 +1,39 @@
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::BaseItemDto;
+use crate::utils::UserIdQuery;
+use crate::JellyfinClient;
+
+impl JellyfinClient {
+    /// Gets a person, such as an actor or director, by name.
+    ///
+    /// The biography is returned as the `overview`, the date of birth as the `premiere_date` and
+    /// the date of death as the `end_date`. The items a person appears in can be listed with
+    /// `get_items`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the person.
+    /// * `user_id` - The ID of the user requesting the person.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the person as a `BaseItemDto` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_person<T: Into<String>>(&self, name: T, user_id: T) -> Result<BaseItemDto> {
+        let endpoint_url = self.item_by_name_url("Persons", &name.into());
+
+        let query = UserIdQuery {
+            user_id: user_id.into(),
+        };
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(&query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/persons.rs"]
+mod tests;
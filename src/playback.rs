@@ -0,0 +1,603 @@
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use futures_util::future::try_join;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use tokio::task::JoinHandle;
+
+use url::Url;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::{BaseItemDto, MediaType};
+use crate::session::{DeviceProfile, PlayMethod};
+use crate::videos::VideoStreamQuery;
+use crate::JellyfinClient;
+
+/// The interval at which Jellyfin expects clients to report playback progress.
+pub const DEFAULT_PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PlaybackProgressInfo {
+    pub item_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_source_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_session_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_stream_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle_stream_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_ticks: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume_level: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_method: Option<PlayMethod>,
+    pub can_seek: bool,
+    pub is_paused: bool,
+    pub is_muted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PlaybackStopInfo {
+    pub item_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_source_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_session_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_ticks: Option<i64>,
+    pub failed: bool,
+}
+
+impl From<&PlaybackProgressInfo> for PlaybackStopInfo {
+    fn from(info: &PlaybackProgressInfo) -> Self {
+        Self {
+            item_id: info.item_id.clone(),
+            session_id: info.session_id.clone(),
+            media_source_id: info.media_source_id.clone(),
+            play_session_id: info.play_session_id.clone(),
+            position_ticks: info.position_ticks,
+            failed: false,
+        }
+    }
+}
+
+/// How an item can be played, as decided by the server.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PlaybackInfoResponse {
+    #[serde(default)]
+    pub media_sources: Vec<MediaSourceInfo>,
+    /// The ID to report playback with, tying the reports to the server's playback decision.
+    pub play_session_id: Option<String>,
+    /// Why the item cannot be played, e.g. `"NotAllowed"` or `"NoCompatibleStream"`.
+    pub error_code: Option<String>,
+}
+
+impl PlaybackInfoResponse {
+    /// Finds one of the media sources, returning `JellyfinError::NotFound` if the item has no such
+    /// media source.
+    pub(crate) fn media_source(
+        &self,
+        item_id: &str,
+        media_source_id: &str,
+    ) -> Result<&MediaSourceInfo> {
+        self.media_sources
+            .iter()
+            .find(|source| source.id == media_source_id)
+            .ok_or_else(|| {
+                JellyfinError::not_found(format!(
+                    "Media source {} of item {}",
+                    media_source_id, item_id
+                ))
+            })
+    }
+}
+
+/// A playable version of an item, such as one of the files of a movie with several versions.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaSourceInfo {
+    pub id: String,
+    pub name: Option<String>,
+    pub path: Option<String>,
+    pub container: Option<String>,
+    pub size: Option<i64>,
+    pub bitrate: Option<i64>,
+    pub run_time_ticks: Option<i64>,
+    /// Whether the client can play the file as is.
+    #[serde(default)]
+    pub supports_direct_play: bool,
+    /// Whether the server can remux the file for the client without re-encoding it.
+    #[serde(default)]
+    pub supports_direct_stream: bool,
+    #[serde(default)]
+    pub supports_transcoding: bool,
+    /// The server-relative URL of the transcoded stream, when transcoding is needed.
+    pub transcoding_url: Option<String>,
+    #[serde(default)]
+    pub media_streams: Vec<MediaStreamInfo>,
+    pub default_audio_stream_index: Option<i64>,
+    pub default_subtitle_stream_index: Option<i64>,
+}
+
+/// The kind of a stream within a media file.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MediaStreamType {
+    Audio,
+    Video,
+    Subtitle,
+    EmbeddedImage,
+    Data,
+    Lyric,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// An audio, video or subtitle stream of a media file.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaStreamInfo {
+    #[serde(rename = "Type", default)]
+    pub type_: MediaStreamType,
+    /// The index of the stream within the media source, as used to select audio and subtitle streams.
+    pub index: i64,
+    pub codec: Option<String>,
+    pub language: Option<String>,
+    pub title: Option<String>,
+    /// A human readable description of the stream, e.g. `"English - AAC - Stereo - Default"`.
+    pub display_title: Option<String>,
+    #[serde(default)]
+    pub is_default: bool,
+    #[serde(default)]
+    pub is_forced: bool,
+    /// Whether the stream is a separate file, such as an external subtitle file.
+    #[serde(default)]
+    pub is_external: bool,
+    #[serde(default)]
+    pub is_text_subtitle_stream: bool,
+    pub channels: Option<i64>,
+    pub channel_layout: Option<String>,
+    pub bit_rate: Option<i64>,
+    pub width: Option<i64>,
+    pub height: Option<i64>,
+    pub video_range: Option<String>,
+}
+
+/// What `decide_playback` tells the server about the client, so it can decide how to play an item.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PlaybackDecisionRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// The formats the client can play. Without one, the server assumes a generic client.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_profile: Option<DeviceProfile>,
+    /// The maximum bitrate of the stream, in bits per second.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_streaming_bitrate: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_ticks: Option<i64>,
+    /// The media source to play. The first one is played when this is `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_source_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_stream_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle_stream_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_direct_play: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_direct_stream: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_transcoding: Option<bool>,
+}
+
+/// How to play a media source, as decided by `decide_playback`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlaybackDecision {
+    /// The client can play the file as is.
+    DirectPlay { url: Url },
+    /// The server remuxes the file into a container the client supports, without re-encoding it.
+    DirectStream { url: Url },
+    /// The server transcodes the file into an HLS stream.
+    Transcode { hls_url: Url },
+}
+
+/// Which parts of the queue `build_play_queue` leaves out.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct PlayQueueOptions {
+    /// Leaves out the intros, even when cinema mode is enabled.
+    pub skip_intros: bool,
+    /// Leaves out the additional parts of videos split into several files.
+    pub skip_additional_parts: bool,
+}
+
+impl JellyfinClient {
+    /// Gets the media sources of an item and how each of them can be played.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item to play.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `PlaybackInfoResponse` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_playback_info<T: Into<String>>(
+        &self,
+        item_id: T,
+    ) -> Result<PlaybackInfoResponse> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/{}/PlaybackInfo", item_id.into()))
+            .expect("Failed to join URL");
+
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Builds the URL to play a media source as is, without any transcoding or remuxing.
+    ///
+    /// The playback info of the item is checked first, so a source the server would have to
+    /// transcode is reported as an error instead of returning a URL that would fail to play.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the item to play.
+    /// * `media_source_id` - The ID of the media source to play, from `get_playback_info`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the stream `Url` if successful, `JellyfinError::NotFound` if the item has no such media source, `JellyfinError::Unsupported` if the media source cannot be direct played, or another `JellyfinError` otherwise.
+    pub async fn direct_play_url<T: Into<String>>(
+        &self,
+        item_id: T,
+        media_source_id: T,
+    ) -> Result<Url> {
+        let (item_id, media_source_id) = (item_id.into(), media_source_id.into());
+        let info = self.get_playback_info(item_id.as_str()).await?;
+
+        let source = info.media_source(&item_id, &media_source_id)?;
+
+        if !source.supports_direct_play {
+            return Err(JellyfinError::Unsupported(format!(
+                "Media source {} cannot be direct played",
+                media_source_id
+            )));
+        }
+
+        self.video_stream_url(
+            item_id,
+            &VideoStreamQuery {
+                static_: Some(true),
+                media_source_id: Some(media_source_id),
+                play_session_id: info.play_session_id,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Asks the server how a video can be played by this client, and builds the URL to play it
+    /// with.
+    ///
+    /// The device profile of the request is sent to the server, which checks it against the media
+    /// source. The first method that works is picked, in order of preference: direct play, direct
+    /// stream, then transcoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the video to play.
+    /// * `request` - The capabilities of the client and where to start playing.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `PlaybackDecision` if successful, `JellyfinError::NotFound` if the item has no such media source, `JellyfinError::Unsupported` if the server cannot play the media source for this client, or another `JellyfinError` otherwise.
+    pub async fn decide_playback<T: Into<String>>(
+        &self,
+        item_id: T,
+        request: &PlaybackDecisionRequest,
+    ) -> Result<PlaybackDecision> {
+        let item_id = item_id.into();
+        let endpoint_url = self
+            .url
+            .join(&format!("/Items/{}/PlaybackInfo", item_id))
+            .expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.post(endpoint_url).json(request))
+            .await?;
+
+        let info: PlaybackInfoResponse =
+            response.json().await.map_err(JellyfinError::NetworkError)?;
+
+        if let Some(error_code) = &info.error_code {
+            return Err(JellyfinError::Unsupported(format!(
+                "Item {} cannot be played: {}",
+                item_id, error_code
+            )));
+        }
+
+        let source = match &request.media_source_id {
+            Some(media_source_id) => info.media_source(&item_id, media_source_id)?,
+            None => info.media_sources.first().ok_or_else(|| {
+                JellyfinError::not_found(format!("Media sources of item {}", item_id))
+            })?,
+        };
+
+        let stream_query = VideoStreamQuery {
+            static_: Some(true),
+            media_source_id: Some(source.id.clone()),
+            play_session_id: info.play_session_id.clone(),
+            ..Default::default()
+        };
+
+        if source.supports_direct_play {
+            let url = self.video_stream_url(item_id, &stream_query)?;
+
+            Ok(PlaybackDecision::DirectPlay { url })
+        } else if source.supports_direct_stream {
+            let url = self.video_stream_url(
+                item_id,
+                &VideoStreamQuery {
+                    container: source.container.clone(),
+                    ..stream_query
+                },
+            )?;
+
+            Ok(PlaybackDecision::DirectStream { url })
+        } else if let (true, Some(transcoding_url)) =
+            (source.supports_transcoding, &source.transcoding_url)
+        {
+            // The server-relative URL already carries the access token.
+            let hls_url = self.url.join(transcoding_url)?;
+
+            Ok(PlaybackDecision::Transcode { hls_url })
+        } else {
+            Err(JellyfinError::Unsupported(format!(
+                "Media source {} cannot be played by this client",
+                source.id
+            )))
+        }
+    }
+
+    /// Reports that playback of an item has started.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - The item being played and the initial playback state.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success if the report was accepted, or a `JellyfinError` otherwise.
+    pub async fn report_playback_start(&self, info: &PlaybackProgressInfo) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join("/Sessions/Playing")
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.post(endpoint_url).json(info))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Reports the progress of an item that is currently playing.
+    ///
+    /// Jellyfin expects a progress report roughly every ten seconds; see `PlaybackSession` for a
+    /// helper that takes care of the timing.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - The current playback state.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success if the report was accepted, or a `JellyfinError` otherwise.
+    pub async fn report_playback_progress(&self, info: &PlaybackProgressInfo) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join("/Sessions/Playing/Progress")
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.post(endpoint_url).json(info))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Reports that playback of an item has stopped.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - The item that was playing and the position it stopped at.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success if the report was accepted, or a `JellyfinError` otherwise.
+    pub async fn report_playback_stopped(&self, info: &PlaybackStopInfo) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join("/Sessions/Playing/Stopped")
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.post(endpoint_url).json(info))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Builds the queue of what plays when a user presses play on an item: the intros (pre-roll
+    /// videos) first, then the item itself, then its additional parts for videos split into
+    /// several files.
+    ///
+    /// Intros are only returned by the server when cinema mode is enabled for the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user playing the item.
+    /// * `item_id` - The ID of the item to play.
+    /// * `options` - Which parts of the queue to leave out.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `BaseItemDto` instances in playback order if successful, or a `JellyfinError` otherwise.
+    pub async fn build_play_queue<T: Into<String>>(
+        &self,
+        user_id: T,
+        item_id: T,
+        options: &PlayQueueOptions,
+    ) -> Result<Vec<BaseItemDto>> {
+        let (user_id, item_id) = (user_id.into(), item_id.into());
+
+        let intros = async {
+            if options.skip_intros {
+                Ok(Vec::new())
+            } else {
+                Ok(self
+                    .get_intros(user_id.as_str(), item_id.as_str())
+                    .await?
+                    .items)
+            }
+        };
+
+        let (item, intros) =
+            try_join(self.get_item(user_id.as_str(), item_id.as_str()), intros).await?;
+
+        let parts = if options.skip_additional_parts || item.media_type != Some(MediaType::Video) {
+            Vec::new()
+        } else {
+            self.get_additional_parts(item_id.as_str(), user_id.as_str())
+                .await?
+                .items
+        };
+
+        let mut queue = intros;
+        queue.push(item);
+        queue.extend(parts);
+
+        Ok(queue)
+    }
+}
+
+/// Keeps the server informed about the playback of a single item.
+///
+/// Starting a session reports the start of playback and spawns a background task that reports the
+/// progress on a fixed interval. The position and pause state can be updated between reports.
+/// Calling `stop` ends the background task and reports the final position as stopped; dropping the
+/// session without stopping it only ends the background task.
+///
+/// The background task runs on the current Tokio runtime. Failed progress reports are skipped, as
+/// the next report supersedes them.
+///
+/// # Examples
+///
+/// ```no_run
+/// use jellyfin_rs::playback::{PlaybackProgressInfo, PlaybackSession};
+/// use jellyfin_rs::JellyfinClient;
+///
+/// async fn play(client: &JellyfinClient) -> jellyfin_rs::err::Result<()> {
+///     let info = PlaybackProgressInfo {
+///         item_id: "item-id".to_string(),
+///         can_seek: true,
+///         ..Default::default()
+///     };
+///
+///     let session = PlaybackSession::start(client.clone(), info).await?;
+///     session.set_position_ticks(600_000_000);
+///     session.set_paused(true);
+///     session.stop().await
+/// }
+/// ```
+#[derive(Debug)]
+pub struct PlaybackSession {
+    client: JellyfinClient,
+    state: Arc<Mutex<PlaybackProgressInfo>>,
+    task: JoinHandle<()>,
+}
+
+impl PlaybackSession {
+    /// Reports the start of playback and begins reporting progress every ten seconds.
+    pub async fn start(client: JellyfinClient, info: PlaybackProgressInfo) -> Result<Self> {
+        Self::start_with_interval(client, info, DEFAULT_PROGRESS_INTERVAL).await
+    }
+
+    /// Reports the start of playback and begins reporting progress on the given interval.
+    pub async fn start_with_interval(
+        client: JellyfinClient,
+        info: PlaybackProgressInfo,
+        interval: Duration,
+    ) -> Result<Self> {
+        client.report_playback_start(&info).await?;
+
+        let state = Arc::new(Mutex::new(info));
+        let task = tokio::spawn({
+            let client = client.clone();
+            let state = state.clone();
+            async move {
+                loop {
+                    tokio::time::sleep(interval).await;
+                    let info = state.lock().unwrap_or_else(PoisonError::into_inner).clone();
+                    let _ = client.report_playback_progress(&info).await;
+                }
+            }
+        });
+
+        Ok(Self {
+            client,
+            state,
+            task,
+        })
+    }
+
+    /// Updates the playback position sent with the next progress report.
+    pub fn set_position_ticks(&self, position_ticks: i64) {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .position_ticks = Some(position_ticks);
+    }
+
+    /// Updates the pause state sent with the next progress report.
+    pub fn set_paused(&self, is_paused: bool) {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_paused = is_paused;
+    }
+
+    /// Returns a snapshot of the playback state that will be reported next.
+    pub fn info(&self) -> PlaybackProgressInfo {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Stops reporting progress and reports the last known position as stopped.
+    pub async fn stop(self) -> Result<()> {
+        self.task.abort();
+        let stop_info = PlaybackStopInfo::from(&self.info());
+        self.client.report_playback_stopped(&stop_info).await
+    }
+}
+
+impl Drop for PlaybackSession {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/playback.rs"]
+mod tests;
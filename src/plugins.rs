@@ -0,0 +1,160 @@
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::JellyfinClient;
+
+/// A configuration page registered by a plugin, as shown in the web dashboard.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConfigurationPageInfo {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub plugin_id: Option<String>,
+    /// The dashboard menu section the page is listed under, e.g. `"server"`.
+    pub menu_section: Option<String>,
+    pub menu_icon: Option<String>,
+    #[serde(default)]
+    pub enable_in_main_menu: bool,
+}
+
+/// A package, such as a plugin, offered by one of the server's repositories.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PackageInfo {
+    pub name: String,
+    pub guid: Option<String>,
+    pub overview: Option<String>,
+    pub description: Option<String>,
+    pub owner: Option<String>,
+    pub category: Option<String>,
+    pub image_url: Option<String>,
+    /// The available versions, newest first.
+    #[serde(default)]
+    pub versions: Vec<VersionInfo>,
+}
+
+/// A version of a package.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VersionInfo {
+    /// The version, as passed to `install_package`.
+    pub version: String,
+    pub changelog: Option<String>,
+    /// The minimum server version the package runs on.
+    pub target_abi: Option<String>,
+    pub source_url: Option<String>,
+    pub checksum: Option<String>,
+    pub timestamp: Option<String>,
+    pub repository_name: Option<String>,
+    /// The repository the version comes from, as passed to `install_package`.
+    pub repository_url: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct ConfigurationPagesQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_in_main_menu: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct InstallPackageQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repository_url: Option<String>,
+}
+
+impl JellyfinClient {
+    /// Gets the configuration pages registered by the installed plugins.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable_in_main_menu` - Filters on pages that are, or are not, listed in the main menu.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `ConfigurationPageInfo` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_configuration_pages(
+        &self,
+        enable_in_main_menu: Option<bool>,
+    ) -> Result<Vec<ConfigurationPageInfo>> {
+        let endpoint_url = self
+            .url
+            .join("/web/ConfigurationPages")
+            .expect("Failed to join URL");
+
+        let response = self
+            .send_authed(
+                self.client
+                    .get(endpoint_url)
+                    .query(&ConfigurationPagesQuery {
+                        enable_in_main_menu,
+                    }),
+            )
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets the packages offered by the server's repositories.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `PackageInfo` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_packages(&self) -> Result<Vec<PackageInfo>> {
+        let endpoint_url = self.url.join("/Packages").expect("Failed to join URL");
+
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Installs or updates a package, such as a plugin, from the configured repositories.
+    ///
+    /// The installation runs in the background on the server. Most plugins only take effect
+    /// after a restart, which `get_system_info` reports through `has_pending_restart` once the
+    /// installation completes. Requires an administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the package.
+    /// * `version` - The version to install, from `VersionInfo::version`, or `None` for the latest version.
+    /// * `repository_url` - The repository to install from, or `None` to use any configured repository.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if the installation was queued, or a `JellyfinError` otherwise.
+    pub async fn install_package<T: Into<String>>(
+        &self,
+        name: T,
+        version: Option<T>,
+        repository_url: Option<T>,
+    ) -> Result<()> {
+        let mut endpoint_url = self
+            .url
+            .join("/Packages/Installed")
+            .expect("Failed to join URL");
+        endpoint_url
+            .path_segments_mut()
+            .expect("Base URL cannot have a path")
+            .push(&name.into());
+
+        let query = InstallPackageQuery {
+            version: version.map(Into::into),
+            repository_url: repository_url.map(Into::into),
+        };
+
+        self.send_authed(self.client.post(endpoint_url).query(&query))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/plugins.rs"]
+mod tests;
@@ -0,0 +1,48 @@
+//! Re-exports of the most commonly used types.
+//!
+//! ```no_run
+//! use jellyfin_rs::prelude::*;
+//! ```
+
+pub use crate::cache::CacheConfig;
+pub use crate::channels::{ChannelItemsQuery, ChannelsQuery};
+pub use crate::display_preferences::{DisplayPreferences, ScrollDirection};
+pub use crate::err::{JellyfinError, Result};
+pub use crate::genres::GenresQuery;
+pub use crate::ids::{ItemId, UserId};
+pub use crate::images::{RemoteImageInfo, RemoteImageQuery, RemoteImageResult};
+pub use crate::items::{
+    BaseItemDto, CollectionType, ExternalUrl, ImageType, ItemCounts, ItemFields, ItemType,
+    ItemsQuery, LatestItemsQuery, LocationType, MediaType, PersonType, PlayHistoryQuery,
+    QueryResult, SimilarItemsQuery, SortOrder, SuggestionsQuery, ThemeMediaResult, UserItemData,
+};
+pub use crate::library::{AddLibraryRequest, LibraryOptions, MediaPathInfo, VirtualFolderInfo};
+pub use crate::media_segments::{MediaSegment, MediaSegmentQuery, MediaSegmentType};
+pub use crate::metadata::{
+    MetadataRefreshMode, RefreshOptions, RemoteSearchInfo, RemoteSearchQuery, RemoteSearchResult,
+};
+pub use crate::music::{
+    ArtistsQuery, AudioTranscodeParams, LyricLine, LyricResponse, TranscodingProtocol,
+};
+pub use crate::playback::{
+    MediaSourceInfo, MediaStreamInfo, MediaStreamType, PlayQueueOptions, PlaybackDecision,
+    PlaybackDecisionRequest, PlaybackInfoResponse, PlaybackProgressInfo, PlaybackSession,
+    PlaybackStopInfo,
+};
+pub use crate::plugins::{ConfigurationPageInfo, PackageInfo, VersionInfo};
+pub use crate::session::{
+    ClientCapabilities, GeneralCommand, GeneralCommandType, PlayMethod, PlayState, RepeatMode,
+    SessionInfo, SessionsQuery,
+};
+pub use crate::startup::StartupConfiguration;
+pub use crate::system::{EndpointInfo, LogFile, PublicSystemInfo, SystemInfo, Version};
+pub use crate::trickplay::TrickplayInfo;
+pub use crate::tv::{EpisodesQuery, SeasonsQuery};
+pub use crate::user::{CreateUserRequest, SubtitleMode, User, UserConfiguration, UserPolicy};
+pub use crate::utils::ClientInfo;
+pub use crate::videos::VideoStreamQuery;
+pub use crate::websocket::{
+    JellyfinWebSocket, PlaybackEvent, PlaybackEventKind, ReconnectPolicy, WebSocketEvent,
+    WebSocketMessage,
+};
+pub use crate::{AuthHeaderStyle, JellyfinClient, RequestMetric};
@@ -0,0 +1,16 @@
+use serde::{Serialize, Serializer};
+
+pub fn serialize<S, T>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut joined = Vec::with_capacity(values.len());
+    for value in values {
+        match serde_json::to_value(value).map_err(serde::ser::Error::custom)? {
+            serde_json::Value::String(s) => joined.push(s),
+            other => joined.push(other.to_string()),
+        }
+    }
+    serializer.serialize_str(&joined.join(","))
+}
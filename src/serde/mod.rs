@@ -1 +1,3 @@
+pub mod comma_separated;
+pub mod pipe_separated;
 pub mod subtitle_mode_serde;
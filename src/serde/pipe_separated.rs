@@ -0,0 +1,10 @@
+use serde::Serializer;
+
+/// Joins values with `|`, for query parameters whose values may themselves contain commas, such
+/// as genre names.
+pub fn serialize<S>(values: &[String], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&values.join("|"))
+}
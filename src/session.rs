@@ -1,13 +1,26 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use futures_util::{stream, Stream};
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
 use serde_json::Value;
+use tokio::time::MissedTickBehavior;
 
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::BaseItemDto;
 use crate::items::FullNowPlayingItem;
-use crate::items::MediaItem;
+use crate::items::ItemType;
+use crate::items::MediaType;
 use crate::items::NowPlayingQueue;
+use crate::JellyfinClient;
+
+/// The default idle cutoff used by `get_now_playing`, matching the web dashboard.
+pub const DEFAULT_ACTIVE_WITHIN_SECONDS: i64 = 960;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct SessionInfo {
     pub play_state: PlayState,    
     pub additional_users: Vec<AdditionalUser>,    
@@ -22,9 +35,9 @@ pub struct SessionInfo {
     pub last_playback_check_in: String,    
     pub device_name: String,    
     pub device_type: Option<String>,    
-    pub now_playing_item: Option<MediaItem>,    
+    pub now_playing_item: Option<BaseItemDto>,    
     pub full_now_playing_item: Option<FullNowPlayingItem>,    
-    pub now_viewing_item: Option<MediaItem>,    
+    pub now_viewing_item: Option<BaseItemDto>,    
     pub device_id: String,    
     pub application_version: String,    
     pub transcoding_info: Option<TranscodingInfo>,    
@@ -32,7 +45,7 @@ pub struct SessionInfo {
     pub supports_media_control: bool,    
     pub supports_remote_control: bool,    
     pub now_playing_queue: Vec<NowPlayingQueue>,    
-    pub now_playing_queue_full_items: Vec<MediaItem>,    
+    pub now_playing_queue_full_items: Vec<BaseItemDto>,    
     pub has_custom_device_name: bool,    
     pub playlist_item_id: Option<String>,    
     pub server_id: String,    
@@ -41,7 +54,7 @@ pub struct SessionInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct PlayState {
     pub position_ticks: Option<i64>,    
     pub can_seek: bool,    
@@ -51,11 +64,30 @@ pub struct PlayState {
     pub audio_stream_index: Option<i64>,    
     pub subtitle_stream_index: Option<i64>,    
     pub media_source_id: Option<String>,    
-    pub play_method: Option<String>,    
-    pub repeat_mode: String,    
+    pub play_method: Option<PlayMethod>,    
+    pub repeat_mode: RepeatMode,    
     pub live_stream_id: Option<String>
 }
 
+/// How an item is being streamed to the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PlayMethod {
+    /// The server converts the media into a format the client supports.
+    Transcode,
+    /// The server remuxes the media into a different container without re-encoding it.
+    DirectStream,
+    /// The client plays the original file as is.
+    DirectPlay,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RepeatMode {
+    #[default]
+    RepeatNone,
+    RepeatAll,
+    RepeatOne,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct AdditionalUser {
@@ -64,7 +96,7 @@ pub struct AdditionalUser {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Capabilities {
     pub playable_media_types: Vec<String>,    
     pub supported_commands: Vec<String>,    
@@ -237,4 +269,374 @@ pub struct TranscodingInfo {
     pub audio_channels: i64,    
     pub hardware_acceleration_type: String,    
     pub transcode_reasons: Vec<String>
-}
\ No newline at end of file
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SessionsQuery {
+    /// Only returns sessions the given user is allowed to remote control.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub controllable_by_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_id: Option<String>,
+    /// Only returns sessions that were active within this many seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_within_seconds: Option<i64>,
+    /// Only returns sessions playing an item of one of these types, e.g. only movie playback.
+    /// The server cannot filter on this, so it is applied after fetching the sessions.
+    #[serde(skip)]
+    pub now_playing_item_types: Vec<ItemType>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct ViewingQuery {
+    session_id: String,
+    item_id: String,
+}
+
+/// The capabilities a client reports for its own session.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ClientCapabilities {
+    pub playable_media_types: Vec<MediaType>,
+    /// The general commands this client can handle, e.g. `"DisplayMessage"` or `"SetVolume"`.
+    pub supported_commands: Vec<String>,
+    pub supports_media_control: bool,
+    pub supports_persistent_identifier: bool,
+}
+
+/// A command a session can be asked to carry out, besides controlling playback.
+///
+/// Clients list the commands they handle in the `supported_commands` of their capabilities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GeneralCommandType {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    PageUp,
+    PageDown,
+    PreviousLetter,
+    NextLetter,
+    ToggleOsd,
+    ToggleContextMenu,
+    Select,
+    Back,
+    TakeScreenshot,
+    /// Sends a key press. Takes a `Key` argument.
+    SendKey,
+    /// Types text into the focused field. Takes a `String` argument.
+    SendString,
+    GoHome,
+    GoToSettings,
+    VolumeUp,
+    VolumeDown,
+    Mute,
+    Unmute,
+    ToggleMute,
+    /// Sets the volume. Takes a `Volume` argument from `0` to `100`.
+    SetVolume,
+    /// Switches the audio track of the playing item. Takes an `Index` argument.
+    SetAudioStreamIndex,
+    /// Switches the subtitles of the playing item, `-1` turning them off. Takes an `Index`
+    /// argument.
+    SetSubtitleStreamIndex,
+    ToggleFullscreen,
+    /// Shows the detail page of an item. Takes `ItemId`, `ItemName` and `ItemType` arguments.
+    DisplayContent,
+    GoToSearch,
+    /// Shows a message. Takes `Header`, `Text` and an optional `TimeoutMs` argument.
+    DisplayMessage,
+    /// Sets the repeat mode. Takes a `RepeatMode` argument.
+    SetRepeatMode,
+    ChannelUp,
+    ChannelDown,
+    Guide,
+    ToggleStats,
+    PlayMediaSource,
+    PlayTrailers,
+    /// Shuffles the play queue or not. Takes a `ShuffleMode` argument.
+    SetShuffleQueue,
+    PlayState,
+    PlayNext,
+    ToggleOsdMenu,
+    Play,
+    /// Limits the streaming bitrate. Takes a `MaxBitrate` argument, in bits per second.
+    SetMaxStreamingBitrate,
+    SetPlaybackOrder,
+}
+
+/// A command sent to a session, with its arguments.
+///
+/// # Examples
+///
+/// ```
+/// use jellyfin_rs::session::{GeneralCommand, GeneralCommandType};
+///
+/// let command = GeneralCommand::new(GeneralCommandType::DisplayMessage)
+///     .with_argument("Header", "Dinner")
+///     .with_argument("Text", "Dinner is ready!");
+/// assert_eq!(command.arguments["Header"], "Dinner");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GeneralCommand {
+    pub name: GeneralCommandType,
+    #[serde(default)]
+    pub arguments: HashMap<String, String>,
+}
+
+impl GeneralCommand {
+    /// A command without arguments.
+    pub fn new(name: GeneralCommandType) -> Self {
+        Self {
+            name,
+            arguments: HashMap::new(),
+        }
+    }
+
+    /// Adds an argument to the command.
+    pub fn with_argument<K: Into<String>, V: ToString>(mut self, key: K, value: V) -> Self {
+        self.arguments.insert(key.into(), value.to_string());
+        self
+    }
+}
+
+impl JellyfinClient {
+    /// Reports the capabilities of the current session, so other clients can control it.
+    ///
+    /// # Arguments
+    ///
+    /// * `capabilities` - The capabilities of this client.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn report_capabilities(&self, capabilities: &ClientCapabilities) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join("/Sessions/Capabilities/Full")
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.post(endpoint_url).json(capabilities))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Reports the item a session is looking at, such as the detail page of a movie, before or
+    /// without playing it.
+    ///
+    /// Other clients see it as the session's `now_viewing_item`.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The ID of the session.
+    /// * `item_id` - The ID of the item being viewed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn report_viewing<T: Into<String>>(&self, session_id: T, item_id: T) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join("/Sessions/Viewing")
+            .expect("Failed to join URL");
+
+        let query = ViewingQuery {
+            session_id: session_id.into(),
+            item_id: item_id.into(),
+        };
+
+        self.send_authed(self.client.post(endpoint_url).query(&query))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Switches the audio track of the item a session is playing.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The ID of the session.
+    /// * `index` - The index of the audio stream, from the `MediaStreamInfo` of the media source.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn set_audio_stream<T: Into<String>>(&self, session_id: T, index: i64) -> Result<()> {
+        self.send_general_command(
+            session_id.into(),
+            &GeneralCommand::new(GeneralCommandType::SetAudioStreamIndex)
+                .with_argument("Index", index),
+        )
+        .await
+    }
+
+    /// Switches the subtitles of the item a session is playing.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The ID of the session.
+    /// * `index` - The index of the subtitle stream, from the `MediaStreamInfo` of the media
+    ///   source, or `-1` to turn subtitles off.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn set_subtitle_stream<T: Into<String>>(
+        &self,
+        session_id: T,
+        index: i64,
+    ) -> Result<()> {
+        self.send_general_command(
+            session_id.into(),
+            &GeneralCommand::new(GeneralCommandType::SetSubtitleStreamIndex)
+                .with_argument("Index", index),
+        )
+        .await
+    }
+
+    /// Sends a command to a session, such as showing a message or changing the volume.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The ID of the session.
+    /// * `command` - The command and its arguments.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn send_general_command<T: Into<String>>(
+        &self,
+        session_id: T,
+        command: &GeneralCommand,
+    ) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Sessions/{}/Command", session_id.into()))
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.post(endpoint_url).json(command))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Gets the sessions connected to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Filters for the session list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `SessionInfo` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_sessions(&self, query: &SessionsQuery) -> Result<Vec<SessionInfo>> {
+        let endpoint_url = self.url.join("/Sessions").expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await?;
+
+        let sessions: Vec<SessionInfo> =
+            response.json().await.map_err(JellyfinError::NetworkError)?;
+
+        if query.now_playing_item_types.is_empty() {
+            return Ok(sessions);
+        }
+
+        Ok(sessions
+            .into_iter()
+            .filter(|session| {
+                session
+                    .now_playing_item
+                    .as_ref()
+                    .and_then(|item| item.type_)
+                    .is_some_and(|type_| query.now_playing_item_types.contains(&type_))
+            })
+            .collect())
+    }
+
+    /// Polls the sessions on a timer, as a fallback for `playback_events` and the `"Sessions"`
+    /// WebSocket messages where WebSockets are blocked, e.g. by a proxy.
+    ///
+    /// The sessions are fetched right away, then every `interval`. A failed fetch is yielded as
+    /// an error without ending the stream, so a temporary network issue can be skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Filters for the sessions, as for `get_sessions`.
+    /// * `interval` - The time between two fetches. Must not be zero.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a stream of the sessions on each fetch, or of a `JellyfinError` when a
+    /// fetch fails, if successful, or `JellyfinError::InvalidInput` if `interval` is zero.
+    pub fn sessions_stream(
+        &self,
+        query: SessionsQuery,
+        interval: Duration,
+    ) -> Result<impl Stream<Item = Result<Vec<SessionInfo>>>> {
+        if interval.is_zero() {
+            return Err(JellyfinError::InvalidInput(
+                "The polling interval must not be zero".to_string(),
+            ));
+        }
+
+        // The timer is created on the first poll, so the stream can be built outside a runtime.
+        Ok(stream::unfold(
+            (self.clone(), query, None),
+            move |(client, query, ticker)| async move {
+                let mut ticker = ticker.unwrap_or_else(|| {
+                    let mut ticker = tokio::time::interval(interval);
+                    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                    ticker
+                });
+                ticker.tick().await;
+                let sessions = client.get_sessions(&query).await;
+                Some((sessions, (client, query, Some(ticker))))
+            },
+        ))
+    }
+
+    /// Gets what is currently playing across the server.
+    ///
+    /// Returns each session that is playing something, paired with the item it plays. The playback
+    /// position is available from the session's `play_state.position_ticks`. Sessions that have
+    /// not been active within `DEFAULT_ACTIVE_WITHIN_SECONDS` are excluded.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_types` - Only returns sessions playing an item of one of these types, or every
+    ///   playing session when empty.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `SessionInfo` and `BaseItemDto` pairs if successful, or a `JellyfinError` otherwise.
+    pub async fn get_now_playing(
+        &self,
+        item_types: &[ItemType],
+    ) -> Result<Vec<(SessionInfo, BaseItemDto)>> {
+        let sessions = self
+            .get_sessions(&SessionsQuery {
+                active_within_seconds: Some(DEFAULT_ACTIVE_WITHIN_SECONDS),
+                now_playing_item_types: item_types.to_vec(),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(sessions
+            .into_iter()
+            .filter_map(|session| {
+                let item = session.now_playing_item.clone()?;
+                Some((session, item))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/session.rs"]
+mod tests;
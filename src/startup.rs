@@ -0,0 +1,119 @@
+use reqwest::StatusCode;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::JellyfinClient;
+
+const STARTUP_COMPLETED: &str = "The startup wizard has already been completed";
+
+/// The initial settings of a server, as set in the startup wizard.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct StartupConfiguration {
+    /// The language of the web interface, e.g. `"en-US"`.
+    #[serde(rename = "UICulture")]
+    pub ui_culture: Option<String>,
+    pub metadata_country_code: Option<String>,
+    pub preferred_metadata_language: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct StartupUser {
+    name: String,
+    password: String,
+}
+
+/// Once the wizard is completed, the startup endpoints are refused to anonymous clients.
+fn startup_error(err: JellyfinError) -> JellyfinError {
+    match err {
+        JellyfinError::HttpRequestError { status, .. }
+            if status == StatusCode::UNAUTHORIZED.as_u16()
+                || status == StatusCode::FORBIDDEN.as_u16() =>
+        {
+            JellyfinError::Forbidden(STARTUP_COMPLETED.to_string())
+        }
+        err => err,
+    }
+}
+
+impl JellyfinClient {
+    /// Gets the initial settings of a server that has not been set up yet.
+    ///
+    /// Like the other startup wizard endpoints, this only works until `startup_complete` is
+    /// called; whether it was can be checked with the `startup_wizard_completed` of
+    /// `get_public_system_info`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `StartupConfiguration` if successful, `JellyfinError::Forbidden` if the startup wizard has already been completed, or another `JellyfinError` otherwise.
+    pub async fn startup_get_config(&self) -> Result<StartupConfiguration> {
+        let endpoint_url = self
+            .url
+            .join("/Startup/Configuration")
+            .expect("Failed to join URL");
+
+        let response = self
+            .send_anonymous(self.client.get(endpoint_url))
+            .await
+            .map_err(startup_error)?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Sets the name and password of the administrator of a server that has not been set up yet.
+    ///
+    /// Only works until `startup_complete` is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the administrator.
+    /// * `password` - The password of the administrator.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, `JellyfinError::Forbidden` if the startup wizard has already been completed, or another `JellyfinError` otherwise.
+    pub async fn startup_set_user<T: Into<String>>(&self, name: T, password: T) -> Result<()> {
+        let endpoint_url = self.url.join("/Startup/User").expect("Failed to join URL");
+
+        // Getting the user makes the server create it, as the web wizard does before updating it.
+        self.send_anonymous(self.client.get(endpoint_url.clone()))
+            .await
+            .map_err(startup_error)?;
+
+        let user = StartupUser {
+            name: name.into(),
+            password: password.into(),
+        };
+
+        self.send_anonymous(self.client.post(endpoint_url).json(&user))
+            .await
+            .map_err(startup_error)?;
+
+        Ok(())
+    }
+
+    /// Completes the startup wizard, after which the server can be logged into.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, `JellyfinError::Forbidden` if the startup wizard has already been completed, or another `JellyfinError` otherwise.
+    pub async fn startup_complete(&self) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join("/Startup/Complete")
+            .expect("Failed to join URL");
+
+        self.send_anonymous(self.client.post(endpoint_url))
+            .await
+            .map_err(startup_error)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/startup.rs"]
+mod tests;
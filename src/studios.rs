@@ -0,0 +1,35 @@
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::BaseItemDto;
+use crate::utils::UserIdQuery;
+use crate::JellyfinClient;
+
+impl JellyfinClient {
+    /// Gets a studio or network by name, including its image tags.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the studio.
+    /// * `user_id` - The ID of the user requesting the studio.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the studio as a `BaseItemDto` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_studio<T: Into<String>>(&self, name: T, user_id: T) -> Result<BaseItemDto> {
+        let endpoint_url = self.item_by_name_url("Studios", &name.into());
+
+        let query = UserIdQuery {
+            user_id: user_id.into(),
+        };
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(&query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/studios.rs"]
+mod tests;
@@ -0,0 +1,344 @@
+use std::fmt;
+
+use bytes::Bytes;
+use futures_util::Stream;
+use url::Url;
+
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::download::body_stream;
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::utils::forbidden_as;
+use crate::JellyfinClient;
+
+/// The first version that expects the user ID as a `userId` query parameter rather than a
+/// `/Users/{userId}/...` path prefix on item endpoints.
+pub const USER_ID_QUERY_VERSION: Version = Version::new(10, 9, 0);
+
+const LOGS_FORBIDDEN: &str = "Reading the server logs requires an administrator";
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PublicSystemInfo {
+    pub local_address: Option<String>,
+    pub server_name: Option<String>,
+    pub version: Option<String>,
+    pub product_name: Option<String>,
+    pub operating_system: Option<String>,
+    pub id: Option<String>,
+    pub startup_wizard_completed: Option<bool>,
+}
+
+/// The full information about the server, only available to administrators.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct SystemInfo {
+    pub local_address: Option<String>,
+    pub server_name: Option<String>,
+    pub version: Option<String>,
+    pub product_name: Option<String>,
+    pub operating_system: Option<String>,
+    pub operating_system_display_name: Option<String>,
+    pub id: Option<String>,
+    pub startup_wizard_completed: Option<bool>,
+    /// Whether the server must be restarted to apply changes, such as an installed plugin.
+    pub has_pending_restart: bool,
+    pub has_update_available: bool,
+    pub is_shutting_down: bool,
+    pub can_self_restart: bool,
+    pub supports_library_monitor: bool,
+    pub web_socket_port_number: Option<i64>,
+    pub program_data_path: Option<String>,
+    pub web_path: Option<String>,
+    pub items_by_name_path: Option<String>,
+    pub cache_path: Option<String>,
+    pub log_path: Option<String>,
+    pub internal_metadata_path: Option<String>,
+    pub transcoding_temp_path: Option<String>,
+    pub system_architecture: Option<String>,
+}
+
+/// A Jellyfin server version, such as `10.9.11`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parses a version string as reported by the server.
+    ///
+    /// Missing components default to zero and anything after the numeric components (such as a
+    /// pre-release suffix) is ignored. Returns `None` if the major version is not a number.
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version
+            .split(['.', '-', '+'])
+            .map(|part| part.trim().parse::<u32>().ok());
+
+        let major = parts.next().flatten()?;
+        let minor = parts.next().flatten().unwrap_or(0);
+        let patch = parts.next().flatten().unwrap_or(0);
+
+        Some(Self::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The information needed to wake the server's machine up from the local network.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WakeOnLanInfo {
+    pub mac_address: Option<String>,
+    pub port: i64,
+}
+
+/// Where a request comes from, as seen by the server.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct EndpointInfo {
+    /// Whether the request comes from the server's own machine.
+    pub is_local: bool,
+    /// Whether the request comes from one of the server's local networks.
+    pub is_in_network: bool,
+}
+
+/// A log file of the server.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct LogFile {
+    /// The file name, as passed to `download_log`.
+    pub name: String,
+    pub date_created: Option<String>,
+    pub date_modified: Option<String>,
+    /// The size of the file, in bytes.
+    pub size: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct LogQuery {
+    name: String,
+}
+
+impl JellyfinClient {
+    /// Gets the publicly available information about the server, without authentication.
+    ///
+    /// The reported server version is remembered and made available through `server_version`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `PublicSystemInfo` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_public_system_info(&self) -> Result<PublicSystemInfo> {
+        let endpoint_url = self
+            .url
+            .join("/System/Info/Public")
+            .expect("Failed to join URL");
+
+        let response = self.send_anonymous(self.client.get(endpoint_url)).await?;
+
+        let info: PublicSystemInfo = response.json().await.map_err(JellyfinError::NetworkError)?;
+
+        // The first version reported is kept; this fails if it was already stored.
+        let _ = self
+            .server_version
+            .set(info.version.as_deref().and_then(Version::parse));
+
+        Ok(info)
+    }
+
+    /// Gets the full information about the server, including whether it needs to be restarted.
+    ///
+    /// Requires an administrator. The reported server version is remembered like with
+    /// `get_public_system_info`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `SystemInfo` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_system_info(&self) -> Result<SystemInfo> {
+        let endpoint_url = self.url.join("/System/Info").expect("Failed to join URL");
+
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+
+        let info: SystemInfo = response.json().await.map_err(JellyfinError::NetworkError)?;
+
+        // The first version reported is kept; this fails if it was already stored.
+        let _ = self
+            .server_version
+            .set(info.version.as_deref().and_then(Version::parse));
+
+        Ok(info)
+    }
+
+    /// Gets the Wake-on-LAN information of the server's network interfaces.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `WakeOnLanInfo` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_wake_on_lan_info(&self) -> Result<Vec<WakeOnLanInfo>> {
+        let endpoint_url = self
+            .url
+            .join("/System/WakeOnLanInfo")
+            .expect("Failed to join URL");
+
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets whether the client is connecting from the server's local network.
+    ///
+    /// Useful to choose between a direct play URL on the LAN and a transcode over the internet.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `EndpointInfo` if successful, or a `JellyfinError` otherwise.
+    pub async fn get_endpoint_info(&self) -> Result<EndpointInfo> {
+        let endpoint_url = self
+            .url
+            .join("/System/Endpoint")
+            .expect("Failed to join URL");
+
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Lists the log files of the server. Requires an administrator.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a vector of `LogFile` instances if successful, `JellyfinError::Forbidden` if the user is not an administrator, or another `JellyfinError` otherwise.
+    pub async fn get_log_files(&self) -> Result<Vec<LogFile>> {
+        let endpoint_url = self.url.join("/System/Logs").expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url))
+            .await
+            .map_err(|err| forbidden_as(err, LOGS_FORBIDDEN))?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Downloads a log file of the server into memory. Requires an administrator.
+    ///
+    /// Log files can grow large; `download_log_stream` reads them chunk by chunk instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the log file, from `get_log_files`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the content of the log file if successful, `JellyfinError::Forbidden` if the user is not an administrator, or another `JellyfinError` otherwise.
+    pub async fn download_log<T: Into<String>>(&self, name: T) -> Result<String> {
+        let response = self.send_log_request(name.into()).await?;
+
+        response.text().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Downloads a log file of the server as a stream of chunks. Requires an administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the log file, from `get_log_files`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a stream of the chunks of the log file if successful, `JellyfinError::Forbidden` if the user is not an administrator, or another `JellyfinError` otherwise.
+    pub async fn download_log_stream<T: Into<String>>(
+        &self,
+        name: T,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let response = self.send_log_request(name.into()).await?;
+
+        Ok(body_stream(response))
+    }
+
+    async fn send_log_request(&self, name: String) -> Result<reqwest::Response> {
+        let endpoint_url = self
+            .url
+            .join("/System/Logs/Log")
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.get(endpoint_url).query(&LogQuery { name }))
+            .await
+            .map_err(|err| forbidden_as(err, LOGS_FORBIDDEN))
+    }
+
+    /// Returns the server version, if it has already been fetched.
+    ///
+    /// This never makes a request; call `fetch_server_version` (or `get_public_system_info`) first.
+    /// The version is shared by all clones of the client.
+    pub fn server_version(&self) -> Option<Version> {
+        self.server_version.get().copied().flatten()
+    }
+
+    /// Returns the server version, fetching it from the public system info on first use.
+    ///
+    /// A version that could not be parsed is remembered too, so the server is asked only once. A
+    /// failed request is not, so the next call tries again.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the server `Version`, or `None` if the server reported a version that
+    /// could not be parsed, if successful, or a `JellyfinError` otherwise.
+    pub async fn fetch_server_version(&self) -> Result<Option<Version>> {
+        self.server_version
+            .get_or_try_init(|| self.request_server_version())
+            .await
+            .copied()
+    }
+
+    async fn request_server_version(&self) -> Result<Option<Version>> {
+        let info = self.get_public_system_info().await?;
+
+        Ok(info.version.as_deref().and_then(Version::parse))
+    }
+
+    /// Builds the URL of an endpoint that Jellyfin 10.9 moved from a `/Users/{userId}/...` path to
+    /// a `userId` query parameter.
+    ///
+    /// The server version is fetched on first use. Servers older than `USER_ID_QUERY_VERSION` get
+    /// `legacy_path`; newer servers, and servers whose version cannot be determined, get `path`
+    /// with the user ID as a query parameter. A version that cannot be parsed is remembered, so it
+    /// is not fetched again on every call; a failed request is not, so the next call tries again.
+    pub(crate) async fn user_scoped_url(
+        &self,
+        user_id: &str,
+        path: &str,
+        legacy_path: &str,
+    ) -> Url {
+        let legacy = matches!(
+            self.fetch_server_version().await,
+            Ok(Some(version)) if version < USER_ID_QUERY_VERSION
+        );
+
+        if legacy {
+            self.url.join(legacy_path).expect("Failed to join URL")
+        } else {
+            let mut url = self.url.join(path).expect("Failed to join URL");
+            url.query_pairs_mut().append_pair("userId", user_id);
+            url
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/system.rs"]
+mod tests;
@@ -0,0 +1,42 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use crate::{err::JellyfinError, tests::init_mock_client};
+
+#[tokio::test]
+async fn for_each_item_concurrent_limits_in_flight_operations() {
+    let (_server, client) = init_mock_client().await;
+    let in_flight = AtomicUsize::new(0);
+    let max_in_flight = AtomicUsize::new(0);
+
+    let ids: Vec<String> = (0..20).map(|i| i.to_string()).collect();
+    let results = client
+        .for_each_item_concurrent(ids, Some(3), |id| {
+            let (in_flight, max_in_flight) = (&in_flight, &max_in_flight);
+            async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(current, Ordering::SeqCst);
+
+                let index: u64 = id.parse().unwrap();
+                tokio::time::sleep(Duration::from_millis(20 - index)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                if index == 5 {
+                    Err(JellyfinError::not_found(id))
+                } else {
+                    Ok(index)
+                }
+            }
+        })
+        .await;
+
+    assert_eq!(max_in_flight.load(Ordering::SeqCst), 3);
+    assert_eq!(results.len(), 20);
+    for (index, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(value) => assert_eq!(value, index as u64),
+            Err(JellyfinError::NotFound(_)) => assert_eq!(index, 5),
+            Err(err) => panic!("unexpected error: {}", err),
+        }
+    }
+}
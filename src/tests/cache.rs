@@ -0,0 +1,211 @@
+use std::time::Duration;
+
+use reqwest::Method;
+use serde_json::json;
+use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    cache::{CacheConfig, ResponseCache},
+    items::ItemsQuery,
+    playback::PlaybackProgressInfo,
+    tests::{init_mock_client, MOCK_USER_ID},
+};
+
+fn key(url: &str) -> Option<String> {
+    ResponseCache::key("user", &Method::GET, &Url::parse(url).expect("Invalid URL"))
+}
+
+#[test]
+fn cache_key_strips_token_and_skips_other_endpoints() {
+    assert_eq!(
+        key("http://example.com/Items?ParentId=movies&api_key=secret").as_deref(),
+        Some("user http://example.com/Items?ParentId=movies")
+    );
+    assert!(key("http://example.com/Users/u/Items/Latest").is_some());
+    assert!(key("http://example.com/Genres/Action").is_some());
+
+    assert_eq!(key("http://example.com/Sessions"), None);
+    assert_eq!(key("http://example.com/Items/Counts"), None);
+    assert_eq!(key("http://example.com/Items/Suggestions"), None);
+    assert_eq!(key("http://example.com/Items/movie/PlaybackInfo"), None);
+    assert_eq!(key("http://example.com/Items/movie/Download"), None);
+    assert_eq!(key("http://example.com/Genres/Action/Images/Primary"), None);
+    assert_eq!(
+        ResponseCache::key(
+            "user",
+            &Method::POST,
+            &Url::parse("http://example.com/Items").expect("Invalid URL")
+        ),
+        None
+    );
+}
+
+#[tokio::test]
+async fn cached_responses_skip_the_server() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    let client = client.with_cache(CacheConfig::default());
+
+    Mock::given(method("GET"))
+        .and(path("/Items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [{ "Id": "movie" }],
+            "TotalRecordCount": 1,
+            "StartIndex": 0
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/UserItems/movie/Rating"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "Likes": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let query = ItemsQuery::default();
+    let first = client.get_items(&query).await?;
+    let cached = client.clone().get_items(&query).await?;
+    assert_eq!(first, cached);
+
+    // Rating an item changes the results, so the next request goes to the server.
+    client
+        .set_item_likes(MOCK_USER_ID, "movie", Some(true))
+        .await?;
+    client.get_items(&query).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cache_expires_and_can_be_invalidated() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    let client = client.with_cache(CacheConfig {
+        ttl: Duration::from_millis(50),
+        ..Default::default()
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/Genres"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [],
+            "TotalRecordCount": 0,
+            "StartIndex": 0
+        })))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let query = Default::default();
+    client.get_genres(&query).await?;
+    client.get_genres(&query).await?;
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    client.get_genres(&query).await?;
+
+    client.invalidate_cache();
+    client.get_genres(&query).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn metadata_updates_read_the_item_from_the_server() -> Result<(), Box<dyn std::error::Error>>
+{
+    let (server, client) = init_mock_client().await;
+    let client = client.with_cache(CacheConfig::default());
+
+    Mock::given(method("GET"))
+        .and(path("/Items/movie"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "Id": "movie" })))
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Items/movie"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.get_item(MOCK_USER_ID, "movie").await?;
+    client
+        .update_item_metadata(MOCK_USER_ID, "movie", |item| {
+            item.name = Some("Renamed".to_string())
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[test]
+fn only_changes_to_the_library_invalidate() {
+    let invalidates = |method: Method, url: &str| {
+        ResponseCache::is_invalidated_by(&method, &Url::parse(url).expect("Invalid URL"))
+    };
+
+    assert!(invalidates(Method::POST, "http://example.com/Items/movie"));
+    assert!(invalidates(
+        Method::POST,
+        "http://example.com/UserItems/movie/Rating"
+    ));
+    assert!(invalidates(
+        Method::DELETE,
+        "http://example.com/Library/VirtualFolders"
+    ));
+    assert!(invalidates(
+        Method::POST,
+        "http://example.com/Sessions/Playing/Stopped"
+    ));
+
+    assert!(!invalidates(Method::GET, "http://example.com/Items/movie"));
+    assert!(!invalidates(
+        Method::POST,
+        "http://example.com/Sessions/Playing/Progress"
+    ));
+    assert!(!invalidates(
+        Method::POST,
+        "http://example.com/Sessions/Capabilities/Full"
+    ));
+    assert!(!invalidates(
+        Method::POST,
+        "http://example.com/Items/movie/PlaybackInfo"
+    ));
+    assert!(!invalidates(
+        Method::POST,
+        "http://example.com/Items/RemoteSearch/Movie"
+    ));
+}
+
+#[tokio::test]
+async fn playback_reports_keep_the_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    let client = client.with_cache(CacheConfig::default());
+
+    Mock::given(method("GET"))
+        .and(path("/Items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [{ "Id": "movie" }],
+            "TotalRecordCount": 1,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Sessions/Playing/Progress"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let query = ItemsQuery::default();
+    client.get_items(&query).await?;
+    client
+        .report_playback_progress(&PlaybackProgressInfo::default())
+        .await?;
+    client.get_items(&query).await?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+use tokio::net::UdpSocket;
+
+use super::{discover_servers_at, DiscoveredServer, DISCOVERY_MESSAGE};
+
+#[tokio::test]
+async fn discover_servers_collects_answers() -> Result<(), Box<dyn std::error::Error>> {
+    let responder = UdpSocket::bind("127.0.0.1:0").await?;
+    let target = responder.local_addr()?;
+
+    tokio::spawn(async move {
+        let mut buf = [0; 64];
+        let (len, from) = responder.recv_from(&mut buf).await.expect("No request");
+        assert_eq!(&buf[..len], DISCOVERY_MESSAGE);
+
+        let answer = json!({
+            "Address": "http://192.168.1.10:8096",
+            "Id": "server-id",
+            "Name": "Living Room",
+            "EndpointAddress": null
+        });
+        for reply in [b"not json".to_vec(), answer.to_string().into_bytes()] {
+            responder
+                .send_to(&reply, from)
+                .await
+                .expect("Failed to answer");
+        }
+    });
+
+    let started = Instant::now();
+    let servers = discover_servers_at(target, Duration::from_millis(300)).await?;
+
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert_eq!(
+        servers,
+        [DiscoveredServer {
+            address: "http://192.168.1.10:8096".to_string(),
+            name: "Living Room".to_string(),
+            id: "server-id".to_string(),
+        }]
+    );
+
+    Ok(())
+}
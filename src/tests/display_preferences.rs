@@ -0,0 +1,66 @@
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    items::SortOrder,
+    tests::{init_mock_client, MOCK_USER_ID},
+};
+
+#[tokio::test]
+async fn display_preferences_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/DisplayPreferences/library"))
+        .and(query_param("UserId", MOCK_USER_ID))
+        .and(query_param("Client", "emby"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Id": "library",
+            "ViewType": "Poster",
+            "SortBy": "SortName",
+            "RememberIndexing": false,
+            "PrimaryImageHeight": 250,
+            "PrimaryImageWidth": 250,
+            "CustomPrefs": { "landing-library": "suggestions", "empty": null },
+            "ScrollDirection": "Horizontal",
+            "ShowBackdrop": true,
+            "RememberSorting": false,
+            "SortOrder": "Ascending",
+            "ShowSidebar": false,
+            "Client": "emby"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/DisplayPreferences/library"))
+        .and(query_param("Client", "emby"))
+        .and(body_partial_json(json!({
+            "ViewType": "List",
+            "SortOrder": "Descending",
+            "RememberSorting": true,
+            "CustomPrefs": { "landing-library": "suggestions" }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut preferences = client
+        .get_display_preferences("library", MOCK_USER_ID, "emby")
+        .await?;
+    assert_eq!(
+        preferences.custom_prefs.get("landing-library"),
+        Some(&Some("suggestions".to_string()))
+    );
+
+    preferences.view_type = Some("List".to_string());
+    preferences.sort_order = SortOrder::Descending;
+    preferences.remember_sorting = true;
+    client
+        .update_display_preferences("library", MOCK_USER_ID, "emby", &preferences)
+        .await?;
+
+    Ok(())
+}
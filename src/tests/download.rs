@@ -0,0 +1,115 @@
+use futures_util::StreamExt;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    download::ContentRange,
+    err::JellyfinError,
+    tests::{init_mock_client, MOCK_TOKEN},
+};
+
+#[tokio::test]
+async fn download_item_streams_body() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    let body = vec![7u8; 64 * 1024];
+
+    Mock::given(method("GET"))
+        .and(path("/Items/movie/Download"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut stream = Box::pin(client.download_item("movie").await?);
+    let mut downloaded = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        downloaded.extend_from_slice(&chunk?);
+    }
+
+    assert_eq!(downloaded, body);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn download_item_forbidden() {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/movie/Download"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
+
+    let result = client.download_item("movie").await;
+
+    assert!(matches!(result, Err(JellyfinError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn download_item_url_carries_token() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    let url = client.download_item_url("movie")?;
+
+    assert_eq!(
+        url.as_str(),
+        format!(
+            "{}/Items/movie/Download?api_key={}",
+            server.uri(),
+            MOCK_TOKEN
+        )
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn download_item_range_sends_range() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/movie/Download"))
+        .and(header("Range", "bytes=100-"))
+        .respond_with(
+            ResponseTemplate::new(206)
+                .insert_header("Content-Range", "bytes 100-103/104")
+                .set_body_bytes(vec![1, 2, 3, 4]),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut download = client.download_item_range("movie", 100, None).await?;
+
+    assert_eq!(
+        download.content_range,
+        Some(ContentRange {
+            start: 100,
+            end: 103,
+            total: Some(104),
+        })
+    );
+    assert_eq!(download.content_length, Some(4));
+
+    let mut downloaded = Vec::new();
+    while let Some(chunk) = download.next().await {
+        downloaded.extend_from_slice(&chunk?);
+    }
+    assert_eq!(downloaded, [1, 2, 3, 4]);
+
+    Ok(())
+}
+
+#[test]
+fn content_range_parses_unknown_total() {
+    assert_eq!(
+        ContentRange::parse("bytes 0-99/*"),
+        Some(ContentRange {
+            start: 0,
+            end: 99,
+            total: None,
+        })
+    );
+    assert_eq!(ContentRange::parse("items 0-99/100"), None);
+}
@@ -0,0 +1,105 @@
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    genres::GenresQuery,
+    items::{ImageType, ItemFields, ItemType},
+    tests::{init_mock_client, MOCK_USER_ID},
+};
+
+#[tokio::test]
+async fn get_genres_filters_item_types() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Genres"))
+        .and(query_param("IncludeItemTypes", "Movie"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [{ "Id": "action", "Name": "Action", "Type": "Genre" }],
+            "TotalRecordCount": 1,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let genres = client
+        .get_genres(&GenresQuery {
+            include_item_types: vec![ItemType::Movie],
+            ..Default::default()
+        })
+        .await?;
+
+    assert_eq!(genres.items[0].name.as_deref(), Some("Action"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_genres_returns_user_counts() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Genres"))
+        .and(query_param("UserId", MOCK_USER_ID))
+        .and(query_param("Fields", "ItemCounts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [
+                { "Id": "action", "Name": "Action", "Type": "Genre", "ChildCount": 142, "MovieCount": 120 },
+                { "Id": "drama", "Name": "Drama", "Type": "Genre", "ChildCount": 7, "RecursiveItemCount": 9 }
+            ],
+            "TotalRecordCount": 2,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let genres = client
+        .get_genres(&GenresQuery {
+            user_id: Some(MOCK_USER_ID.to_string()),
+            fields: vec![ItemFields::ItemCounts],
+            ..Default::default()
+        })
+        .await?;
+
+    assert_eq!(genres.items[0].child_count, Some(142));
+    assert_eq!(genres.items[0].recursive_item_count, None);
+    assert_eq!(genres.items[1].recursive_item_count, Some(9));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_genre_escapes_name() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Genres/Action%2FAdventure"))
+        .and(query_param("UserId", MOCK_USER_ID))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Id": "genre-id",
+            "Name": "Action/Adventure",
+            "Type": "Genre",
+            "ImageTags": { "Primary": "primary-tag" },
+            "BackdropImageTags": ["backdrop-tag"]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let genre = client.get_genre("Action/Adventure", MOCK_USER_ID).await?;
+
+    assert_eq!(genre.type_, Some(ItemType::Genre));
+    assert_eq!(
+        genre
+            .image_tags
+            .get(&ImageType::Primary)
+            .map(String::as_str),
+        Some("primary-tag")
+    );
+    assert_eq!(genre.backdrop_image_tags, ["backdrop-tag"]);
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    ids::{ItemId, UserId},
+    tests::{init_mock_client, mock_user_auth, MOCK_USER_ID},
+};
+
+#[test]
+fn ids_convert_from_and_into_strings() -> Result<(), serde_json::Error> {
+    let user_id = UserId::from("user");
+    assert_eq!(user_id, UserId::from("user".to_string()));
+    assert_eq!(user_id.to_string(), "user");
+    assert_eq!(String::from(user_id.clone()), "user");
+
+    assert_eq!(serde_json::to_value(&user_id)?, json!("user"));
+    assert_eq!(
+        serde_json::from_value::<ItemId>(json!("movie"))?,
+        ItemId::from("movie")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn typed_ids_are_accepted() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/Users/{}", MOCK_USER_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_user_auth().user))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/Items/movie"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "Id": "movie" })))
+        .mount(&server)
+        .await;
+
+    let user_id = UserId::from(MOCK_USER_ID);
+    let item_id = ItemId::from("movie");
+
+    let user = client.get_user_by_id(&user_id).await?;
+    assert_eq!(user.id, MOCK_USER_ID);
+
+    let item = client.get_item(&user_id, &item_id).await?;
+    assert_eq!(item.id, item_id.as_str());
+
+    // Plain strings are still accepted.
+    client.get_item(MOCK_USER_ID, "movie").await?;
+
+    Ok(())
+}
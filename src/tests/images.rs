@@ -0,0 +1,113 @@
+use std::sync::{Arc, Mutex};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde_json::json;
+use wiremock::matchers::{body_string, header, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{images::RemoteImageQuery, items::ImageType, tests::init_mock_client};
+
+#[tokio::test]
+async fn get_remote_images_filters_by_type() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/movie/RemoteImages"))
+        .and(query_param("Type", "Backdrop"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Images": [
+                {
+                    "ProviderName": "TheMovieDb",
+                    "Url": "https://image.example/backdrop.jpg",
+                    "Width": 1920,
+                    "Height": 1080,
+                    "Type": "Backdrop"
+                }
+            ],
+            "TotalRecordCount": 1,
+            "Providers": ["TheMovieDb"]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = client
+        .get_remote_images(
+            "movie",
+            &RemoteImageQuery {
+                type_: Some(ImageType::Backdrop),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    assert_eq!(result.total_record_count, 1);
+    assert_eq!(result.images[0].type_, Some(ImageType::Backdrop));
+    assert_eq!(result.images[0].width, Some(1920));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn download_remote_image_sends_url() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Items/movie/RemoteImages/Download"))
+        .and(query_param("Type", "Primary"))
+        .and(query_param("ImageUrl", "https://image.example/poster.jpg"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .download_remote_image(
+            "movie",
+            ImageType::Primary,
+            "https://image.example/poster.jpg",
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn upload_item_image_reports_progress() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    let image = vec![0xffu8; 100 * 1024];
+    let encoded = STANDARD.encode(&image);
+
+    Mock::given(method("POST"))
+        .and(path("/Items/movie/Images/Backdrop"))
+        .and(header("Content-Type", "image/jpeg"))
+        .and(header("Content-Length", encoded.len().to_string().as_str()))
+        .and(body_string(encoded.clone()))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    client
+        .upload_item_image("movie", ImageType::Backdrop, "image/jpeg", &image)
+        .await?;
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    client
+        .upload_item_image_with_progress("movie", ImageType::Backdrop, "image/jpeg", &image, {
+            let reports = reports.clone();
+            move |uploaded, total| reports.lock().unwrap().push((uploaded, total))
+        })
+        .await?;
+
+    let total = encoded.len() as u64;
+    let reports = reports.lock().unwrap();
+    assert_eq!(
+        reports.as_slice(),
+        [(65536, total), (131072, total), (total, total)]
+    );
+
+    Ok(())
+}
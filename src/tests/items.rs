@@ -0,0 +1,759 @@
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::err::JellyfinError;
+use crate::items::{
+    BaseItemDto, CollectionType, ExternalUrl, ImageType, ItemFields, ItemType, ItemsQuery,
+    LatestItemsQuery, LocationType, MediaType, PersonType, PlayHistoryQuery, SimilarItemsQuery,
+    SortOrder, SuggestionsQuery,
+};
+use crate::tests::{init_mock_client, mock_user_auth, MOCK_USER_ID};
+
+fn query_string<Q: serde::Serialize>(query: &Q) -> Option<String> {
+    reqwest::Client::new()
+        .get("http://example.com")
+        .query(query)
+        .build()
+        .expect("Failed to build request")
+        .url()
+        .query()
+        .map(String::from)
+}
+
+#[test]
+fn suggestions_query_joins_lists() {
+    let query = SuggestionsQuery {
+        media_type: vec![MediaType::Video],
+        type_: vec![ItemType::Movie, ItemType::Series],
+        limit: Some(12),
+        exclude_watched: true,
+    };
+
+    // `exclude_watched` is applied to the results and never sent.
+    assert_eq!(
+        query_string(&query).as_deref(),
+        Some("MediaType=Video&Type=Movie%2CSeries&Limit=12")
+    );
+}
+
+#[test]
+fn suggestions_query_omits_unset_fields() {
+    assert_eq!(query_string(&SuggestionsQuery::default()), None);
+}
+
+#[test]
+fn items_query_targets_parent() {
+    let query = ItemsQuery {
+        user_id: Some("user".to_string()),
+        parent_id: Some("boxset".to_string()),
+        include_item_types: vec![ItemType::Movie],
+        sort_order: Some(SortOrder::Descending),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        query_string(&query).as_deref(),
+        Some("UserId=user&ParentId=boxset&IncludeItemTypes=Movie&SortOrder=Descending")
+    );
+}
+
+#[test]
+fn base_item_dto_deserializes_location_and_blur_hashes() {
+    let item: BaseItemDto = serde_json::from_str(
+        r#"{
+            "Name": "Pilot",
+            "Id": "episode",
+            "Type": "Episode",
+            "LocationType": "Virtual",
+            "ImageTags": { "Primary": "tag" },
+            "ImageBlurHashes": { "Primary": { "tag": "LEHV6nWB2yk8pyo0adR*.7kCMdnj" } }
+        }"#,
+    )
+    .expect("Failed to deserialize item");
+
+    assert_eq!(item.location_type, Some(LocationType::Virtual));
+    assert_eq!(
+        item.image_tags.get(&ImageType::Primary).map(String::as_str),
+        Some("tag")
+    );
+    assert_eq!(
+        item.image_blur_hashes[&ImageType::Primary]
+            .get("tag")
+            .map(String::as_str),
+        Some("LEHV6nWB2yk8pyo0adR*.7kCMdnj")
+    );
+}
+
+async fn mount_server_version(server: &wiremock::MockServer, version: &str) {
+    Mock::given(method("GET"))
+        .and(path("/System/Info/Public"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "Version": version })))
+        .mount(server)
+        .await;
+}
+
+fn empty_query_result() -> serde_json::Value {
+    json!({ "Items": [], "TotalRecordCount": 0, "StartIndex": 0 })
+}
+
+#[tokio::test]
+async fn get_intros_uses_user_id_query_on_10_9() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    mount_server_version(&server, "10.9.11").await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/movie/Intros"))
+        .and(query_param("userId", MOCK_USER_ID))
+        .respond_with(ResponseTemplate::new(200).set_body_json(empty_query_result()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.get_intros(MOCK_USER_ID, "movie").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_intros_uses_user_path_before_10_9() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    mount_server_version(&server, "10.8.13").await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/Users/{}/Items/movie/Intros", MOCK_USER_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(empty_query_result()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.get_intros(MOCK_USER_ID, "movie").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_item_tag_keeps_unmodeled_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    mount_server_version(&server, "10.9.11").await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/movie"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Id": "movie",
+            "Name": "Movie",
+            "Tags": ["HDR"],
+            "ProviderIds": { "Tmdb": "603" }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/Items/movie"))
+        .and(body_json(json!({
+            "Id": "movie",
+            "Name": "Movie",
+            "Tags": ["HDR", "4K"],
+            "ProviderIds": { "Tmdb": "603" }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let item = client.add_item_tag(MOCK_USER_ID, "movie", "4K").await?;
+
+    assert_eq!(item.tags, ["HDR", "4K"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_item_tag_rejects_empty_tag() {
+    let (server, client) = init_mock_client().await;
+
+    let result = client.add_item_tag(MOCK_USER_ID, "movie", "  ").await;
+
+    assert!(matches!(result, Err(JellyfinError::InvalidInput(_))));
+    let requests = server.received_requests().await.unwrap_or_default();
+    assert!(requests
+        .iter()
+        .all(|request| request.url.path() != "/Items/movie"));
+}
+
+#[test]
+fn collection_type_keeps_unknown_values() -> Result<(), serde_json::Error> {
+    let types: Vec<CollectionType> = serde_json::from_value(json!(["tvshows", "mixed"]))?;
+
+    assert_eq!(
+        types,
+        [
+            CollectionType::TvShows,
+            CollectionType::Unknown("mixed".to_string())
+        ]
+    );
+    assert_eq!(serde_json::to_value(&types)?, json!(["tvshows", "mixed"]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_user_views_types_collections() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    mount_server_version(&server, "10.9.11").await;
+
+    Mock::given(method("GET"))
+        .and(path("/UserViews"))
+        .and(query_param("userId", MOCK_USER_ID))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [
+                { "Id": "movies", "CollectionType": "movies" },
+                { "Id": "music", "CollectionType": "music" }
+            ],
+            "TotalRecordCount": 2,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let views = client.get_user_views(MOCK_USER_ID).await?;
+
+    assert_eq!(views.items[0].collection_type, Some(CollectionType::Movies));
+    assert_eq!(views.items[1].collection_type, Some(CollectionType::Music));
+
+    Ok(())
+}
+
+#[test]
+fn items_query_filters_people() {
+    let query = ItemsQuery {
+        person_ids: vec!["keanu".to_string(), "carrie-anne".to_string()],
+        person_types: vec![PersonType::Actor, PersonType::GuestStar],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        query_string(&query).as_deref(),
+        Some("PersonIds=keanu%2Ccarrie-anne&PersonTypes=Actor%2CGuestStar")
+    );
+    assert_eq!(query_string(&ItemsQuery::default()), None);
+}
+
+#[test]
+fn items_query_combines_search_term_with_filters() {
+    let query = ItemsQuery {
+        include_item_types: vec![ItemType::Movie],
+        search_term: Some("matrix".to_string()),
+        genres: vec!["Action".to_string(), "Science Fiction, Fantasy".to_string()],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        query_string(&query).as_deref(),
+        Some("IncludeItemTypes=Movie&SearchTerm=matrix&Genres=Action%7CScience+Fiction%2C+Fantasy")
+    );
+
+    let blank = ItemsQuery {
+        search_term: Some("  ".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(query_string(&blank), None);
+}
+
+#[test]
+fn items_query_filters_ratings_and_overview() {
+    let query = ItemsQuery {
+        min_community_rating: Some(7.5),
+        min_critic_rating: Some(80.0),
+        has_overview: Some(false),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        query_string(&query).as_deref(),
+        Some("MinCommunityRating=7.5&MinCriticRating=80.0&HasOverview=false")
+    );
+}
+
+#[test]
+fn items_query_selects_image_types() {
+    let query = ItemsQuery {
+        enable_images: Some(true),
+        enable_image_types: vec![ImageType::Primary, ImageType::Backdrop],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        query_string(&query).as_deref(),
+        Some("EnableImages=true&EnableImageTypes=Primary%2CBackdrop")
+    );
+}
+
+#[test]
+fn items_query_uses_field_presets() {
+    let query = ItemsQuery {
+        fields: ItemFields::list_preset(),
+        ..Default::default()
+    };
+    assert_eq!(
+        query_string(&query).as_deref(),
+        Some("Fields=PrimaryImageAspectRatio%2CChildCount")
+    );
+
+    let detail = ItemFields::detail_preset();
+    assert!(detail.contains(&ItemFields::Overview));
+    assert!(detail.contains(&ItemFields::MediaSources));
+}
+
+#[test]
+fn base_item_dto_deserializes_user_data() -> Result<(), serde_json::Error> {
+    let item: BaseItemDto = serde_json::from_value(json!({
+        "Id": "episode",
+        "UserData": {
+            "PlayedPercentage": 42.5,
+            "PlaybackPositionTicks": 12000000000i64,
+            "PlayCount": 1,
+            "IsFavorite": true,
+            "Played": false,
+            "Key": "episode-key"
+        }
+    }))?;
+
+    let user_data = item.user_data.expect("Missing user data");
+    assert_eq!(user_data.played_percentage, Some(42.5));
+    assert_eq!(user_data.playback_position_ticks, 12000000000);
+    assert!(user_data.is_favorite);
+    assert!(!user_data.played);
+
+    let item: BaseItemDto = serde_json::from_value(json!({ "Id": "episode" }))?;
+    assert_eq!(item.user_data, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn set_item_likes_and_clear() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/UserItems/track/Rating"))
+        .and(query_param("userId", MOCK_USER_ID))
+        .and(query_param("Likes", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Likes": true,
+            "ItemId": "track"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/UserItems/track/Rating"))
+        .and(query_param("userId", MOCK_USER_ID))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ItemId": "track" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let user_data = client
+        .set_item_likes(MOCK_USER_ID, "track", Some(true))
+        .await?;
+    assert_eq!(user_data.likes, Some(true));
+
+    let user_data = client.set_item_likes(MOCK_USER_ID, "track", None).await?;
+    assert_eq!(user_data.likes, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_latest_items_grouped_by_series() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/Latest"))
+        .and(query_param("userId", MOCK_USER_ID))
+        .and(query_param("IncludeItemTypes", "Episode"))
+        .and(query_param("Fields", "ChildCount,Overview"))
+        .and(query_param("GroupItems", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "Id": "series", "Type": "Series", "ChildCount": 3 }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let latest = client
+        .get_latest_items(
+            MOCK_USER_ID,
+            &LatestItemsQuery {
+                include_item_types: vec![ItemType::Episode],
+                fields: vec![ItemFields::ChildCount, ItemFields::Overview],
+                group_items: Some(true),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    assert_eq!(latest[0].type_, Some(ItemType::Series));
+    assert_eq!(latest[0].child_count, Some(3));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_latest_items_deduplicates_across_libraries() -> Result<(), Box<dyn std::error::Error>>
+{
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/Latest"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "Id": "series" },
+            { "Id": "movie" },
+            { "Id": "series" }
+        ])))
+        .mount(&server)
+        .await;
+
+    let latest = client
+        .get_latest_items(MOCK_USER_ID, &LatestItemsQuery::default())
+        .await?;
+    assert_eq!(latest.len(), 3);
+
+    let latest = client
+        .get_latest_items(
+            MOCK_USER_ID,
+            &LatestItemsQuery {
+                deduplicate: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+    let ids: Vec<&str> = latest.iter().map(|item| item.id.as_str()).collect();
+    assert_eq!(ids, ["series", "movie"]);
+
+    Ok(())
+}
+
+fn items_page(ids: &[&str], total_record_count: i64, start_index: i64) -> serde_json::Value {
+    json!({
+        "Items": ids.iter().map(|id| json!({ "Id": id })).collect::<Vec<_>>(),
+        "TotalRecordCount": total_record_count,
+        "StartIndex": start_index
+    })
+}
+
+#[tokio::test]
+async fn get_all_items_collects_every_page() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    for (start_index, ids) in [("0", ["a", "b"].as_slice()), ("2", ["c"].as_slice())] {
+        Mock::given(method("GET"))
+            .and(path("/Items"))
+            .and(query_param("StartIndex", start_index))
+            .and(query_param("Limit", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(items_page(
+                ids,
+                3,
+                start_index.parse()?,
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let query = ItemsQuery {
+        limit: Some(2),
+        ..Default::default()
+    };
+    let items = client.get_all_items(&query, 10).await?;
+
+    let ids: Vec<_> = items.iter().map(|item| item.id.as_str()).collect();
+    assert_eq!(ids, ["a", "b", "c"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_all_items_stops_above_max_items() {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(items_page(&["a"], 500_000, 0)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = client.get_all_items(&ItemsQuery::default(), 1000).await;
+
+    assert!(matches!(
+        result,
+        Err(JellyfinError::TooManyItems {
+            matched: 500_000,
+            max: 1000
+        })
+    ));
+}
+
+#[tokio::test]
+async fn get_item_ancestors_orders_from_root() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/episode/Ancestors"))
+        .and(query_param("UserId", MOCK_USER_ID))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "Id": "season", "Name": "Season 1" },
+            { "Id": "series", "Name": "The Expanse" },
+            { "Id": "library", "Name": "Shows" }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let ancestors = client.get_item_ancestors("episode", MOCK_USER_ID).await?;
+
+    let ids: Vec<_> = ancestors.iter().map(|item| item.id.as_str()).collect();
+    assert_eq!(ids, ["library", "series", "season"]);
+    assert_eq!(ancestors[0].name.as_deref(), Some("Shows"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_similar_items_excludes_artists() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/album/Similar"))
+        .and(query_param("UserId", MOCK_USER_ID))
+        .and(query_param("ExcludeArtistIds", "artist1,artist2"))
+        .and(query_param("Limit", "12"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(items_page(&["other"], 1, 0)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let query = SimilarItemsQuery {
+        user_id: Some(MOCK_USER_ID.to_string()),
+        exclude_artist_ids: vec!["artist1".to_string(), "artist2".to_string()],
+        limit: Some(12),
+        ..Default::default()
+    };
+    let similar = client.get_similar_items("album", &query).await?;
+
+    assert_eq!(similar.items[0].id, "other");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_ordered_user_views_follows_user_order() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    let mut user = mock_user_auth().user;
+    user.configuration.ordered_views = vec!["music".to_string(), "SHOWS".to_string()];
+
+    Mock::given(method("GET"))
+        .and(path("/UserViews"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(items_page(
+            &["movies", "shows", "photos", "music"],
+            4,
+            0,
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/Users/{}", MOCK_USER_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let views = client.get_ordered_user_views(MOCK_USER_ID).await?;
+
+    let ids: Vec<_> = views.items.iter().map(|view| view.id.as_str()).collect();
+    assert_eq!(ids, ["music", "shows", "movies", "photos"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_items_lists_books_and_audiobooks() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items"))
+        .and(query_param("ParentId", "books"))
+        .and(query_param("IncludeItemTypes", "Book,AudioBook"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [
+                {
+                    "Name": "The Fellowship of the Ring",
+                    "Id": "book",
+                    "Type": "Book",
+                    "MediaType": "Book",
+                    "SeriesName": "The Lord of the Rings",
+                    "IndexNumber": 1,
+                    "MediaSources": [{ "Id": "book", "Container": "epub", "Size": 1024 }],
+                    "UserData": { "PlayedPercentage": 42.0, "Played": false }
+                },
+                {
+                    "Name": "The Hobbit",
+                    "Id": "audiobook",
+                    "Type": "AudioBook",
+                    "MediaType": "Audio",
+                    "RunTimeTicks": 396000000000i64
+                }
+            ],
+            "TotalRecordCount": 2,
+            "StartIndex": 0
+        })))
+        .mount(&server)
+        .await;
+
+    let books = client
+        .get_items(&ItemsQuery {
+            parent_id: Some("books".to_string()),
+            include_item_types: vec![ItemType::Book, ItemType::AudioBook],
+            ..Default::default()
+        })
+        .await?;
+
+    let book = &books.items[0];
+    assert_eq!(book.type_, Some(ItemType::Book));
+    assert_eq!(book.media_type, Some(MediaType::Book));
+    assert_eq!(book.series_name.as_deref(), Some("The Lord of the Rings"));
+    assert_eq!(book.index_number, Some(1));
+    assert_eq!(book.media_sources[0].container.as_deref(), Some("epub"));
+    assert_eq!(
+        book.user_data
+            .as_ref()
+            .and_then(|data| data.played_percentage),
+        Some(42.0)
+    );
+
+    let audiobook = &books.items[1];
+    assert_eq!(audiobook.type_, Some(ItemType::AudioBook));
+    assert_eq!(audiobook.media_type, Some(MediaType::Audio));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exclude_watched_drops_played_items() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/movie/Similar"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [
+                { "Id": "watched", "UserData": { "Played": true } },
+                { "Id": "unwatched", "UserData": { "Played": false } },
+                { "Id": "unknown" }
+            ],
+            "TotalRecordCount": 3,
+            "StartIndex": 0
+        })))
+        .mount(&server)
+        .await;
+
+    let query = SimilarItemsQuery {
+        user_id: Some(MOCK_USER_ID.to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        client.get_similar_items("movie", &query).await?.items.len(),
+        3
+    );
+
+    let similar = client
+        .get_similar_items(
+            "movie",
+            &SimilarItemsQuery {
+                exclude_watched: true,
+                ..query
+            },
+        )
+        .await?;
+    let ids: Vec<&str> = similar.items.iter().map(|item| item.id.as_str()).collect();
+    assert_eq!(ids, ["unwatched", "unknown"]);
+    // The total is the server's, across all pages, not the number of items left on this one.
+    assert_eq!(similar.total_record_count, 3);
+
+    Ok(())
+}
+
+#[test]
+fn base_item_dto_deserializes_provider_ids_and_external_urls() -> Result<(), serde_json::Error> {
+    let item: BaseItemDto = serde_json::from_value(json!({
+        "Id": "movie",
+        "ProviderIds": { "IMDB": "tt0133093", "Tmdb": "603" },
+        "ExternalUrls": [{ "Name": "IMDb", "Url": "https://www.imdb.com/title/tt0133093" }]
+    }))?;
+
+    assert_eq!(item.provider_id("imdb"), Some("tt0133093"));
+    assert_eq!(item.provider_id("TMDB"), Some("603"));
+    assert_eq!(item.provider_id("tvdb"), None);
+    assert_eq!(
+        item.external_urls,
+        [ExternalUrl {
+            name: "IMDb".to_string(),
+            url: "https://www.imdb.com/title/tt0133093".to_string(),
+        }]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_play_history_sorts_played_items() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items"))
+        .and(query_param("UserId", MOCK_USER_ID))
+        .and(query_param("IsPlayed", "true"))
+        .and(query_param("Recursive", "true"))
+        .and(query_param("SortBy", "DatePlayed"))
+        .and(query_param("SortOrder", "Descending"))
+        .and(query_param("IncludeItemTypes", "Movie,Episode"))
+        .and(query_param("Limit", "20"))
+        .and(query_param("EnableUserData", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [{
+                "Id": "movie",
+                "UserData": { "Played": true, "LastPlayedDate": "2026-10-15T20:00:00Z" }
+            }],
+            "TotalRecordCount": 1,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let history = client
+        .get_play_history(
+            MOCK_USER_ID,
+            &PlayHistoryQuery {
+                include_item_types: vec![ItemType::Movie, ItemType::Episode],
+                limit: Some(20),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    assert_eq!(
+        history.items[0]
+            .user_data
+            .as_ref()
+            .and_then(|data| data.last_played_date.as_deref()),
+        Some("2026-10-15T20:00:00Z")
+    );
+
+    Ok(())
+}
@@ -1,7 +1,18 @@
 use dotenv::dotenv;
 use std::error::Error;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
-use crate::JellyfinClient;
+use crate::{
+    user::{AuthKind, User, UserAuth},
+    JellyfinClient,
+};
+
+mod mock;
+mod send;
+
+pub const MOCK_TOKEN: &str = "mock-token";
+pub const MOCK_USER_ID: &str = "mock-user-id";
 
 pub fn get_config() -> (String, String, String) {
     dotenv().ok();
@@ -22,6 +33,51 @@ pub async fn init_test_client() -> Result<JellyfinClient, Box<dyn Error>> {
     Ok(client)
 }
 
+/// The body returned by a successful `/Users/AuthenticateByName` call on the mock server.
+pub fn mock_user_auth() -> UserAuth {
+    UserAuth {
+        user: User {
+            name: "test".to_string(),
+            id: MOCK_USER_ID.to_string(),
+            ..Default::default()
+        },
+        access_token: MOCK_TOKEN.to_string(),
+        server_id: "mock-server-id".to_string(),
+        ..Default::default()
+    }
+}
+
+/// Returns a client for `url` that is already authenticated as `mock_user_auth`, for tests whose
+/// server cannot answer a login, such as a bare WebSocket server.
+pub async fn mock_authed_client(url: &str) -> JellyfinClient {
+    let client = JellyfinClient::new(url)
+        .await
+        .expect("Failed to create client");
+    client.set_auth(AuthKind::User(Box::new(mock_user_auth())));
+
+    client
+}
+
+/// Starts a mock Jellyfin server and returns a client authenticated against it.
+///
+/// Unlike `init_test_client`, this does not require a running Jellyfin instance. Tests mount the
+/// endpoints they exercise on the returned server.
+pub async fn init_mock_client() -> (MockServer, JellyfinClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Users/AuthenticateByName"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_user_auth()))
+        .mount(&server)
+        .await;
+
+    let client = JellyfinClient::new_auth_name(server.uri().as_str(), "test", "test")
+        .await
+        .expect("Failed to auth against mock server");
+
+    (server, client)
+}
+
 #[tokio::test]
 async fn test_new_with_valid_url() -> Result<(), Box<dyn Error>> {
     let test_url = "http://example.com";
@@ -34,7 +90,7 @@ async fn test_new_with_valid_url() -> Result<(), Box<dyn Error>> {
         "URL should be correctly parsed"
     );
 
-    assert!(result.auth.is_none(), "Auth should be None");
+    assert!(result.auth.read().unwrap().is_none(), "Auth should be None");
 
     Ok(())
 }
@@ -50,3 +106,25 @@ async fn test_new_with_invalid_url() {
         "Function should return an Err for an invalid URL"
     );
 }
+
+#[tokio::test]
+async fn test_clones_share_auth() -> Result<(), Box<dyn Error>> {
+    let client = JellyfinClient::new("http://example.com").await?;
+    let cloned = client.clone();
+
+    assert!(
+        cloned.auth_header().is_err(),
+        "Clone should start unauthenticated"
+    );
+
+    client.set_auth(AuthKind::User(Box::new(mock_user_auth())));
+
+    assert!(
+        cloned
+            .auth_header()?
+            .contains(&format!("Token=\"{}\"", MOCK_TOKEN)),
+        "Clone should see the authentication set through the original client"
+    );
+
+    Ok(())
+}
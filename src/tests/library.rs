@@ -0,0 +1,167 @@
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    err::JellyfinError,
+    items::CollectionType,
+    library::{AddLibraryRequest, LibraryOptions, MediaPathInfo},
+    tests::init_mock_client,
+};
+
+#[tokio::test]
+async fn get_virtual_folders_keeps_unmodeled_options() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Library/VirtualFolders"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "Name": "Movies",
+            "Locations": ["/media/movies"],
+            "CollectionType": "movies",
+            "ItemId": "movies-folder",
+            "RefreshStatus": "Idle",
+            "LibraryOptions": {
+                "EnableRealtimeMonitor": true,
+                "PathInfos": [{ "Path": "/media/movies" }],
+                "AutomaticRefreshIntervalDays": 30
+            }
+        }])))
+        .mount(&server)
+        .await;
+
+    let folders = client.get_virtual_folders().await?;
+
+    assert_eq!(folders[0].collection_type, Some(CollectionType::Movies));
+    assert_eq!(folders[0].locations, ["/media/movies"]);
+    let options = folders[0]
+        .library_options
+        .as_ref()
+        .expect("Missing options");
+    assert_eq!(options.enable_realtime_monitor, Some(true));
+    assert_eq!(options.path_infos[0].path, "/media/movies");
+    assert_eq!(
+        serde_json::to_value(options)?,
+        json!({
+            "EnableRealtimeMonitor": true,
+            "PathInfos": [{ "Path": "/media/movies" }],
+            "AutomaticRefreshIntervalDays": 30
+        })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_and_remove_virtual_folder() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Library/VirtualFolders"))
+        .and(query_param("Name", "Movies"))
+        .and(query_param("CollectionType", "movies"))
+        .and(query_param("RefreshLibrary", "true"))
+        .and(query_param("Paths", "/media/movies"))
+        .and(query_param("Paths", "/media/films"))
+        .and(body_json(json!({
+            "LibraryOptions": {
+                "PreferredMetadataLanguage": "en",
+                "PathInfos": [{ "Path": "/media/movies" }]
+            }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/Library/VirtualFolders"))
+        .and(query_param("Name", "Movies"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .add_virtual_folder(&AddLibraryRequest {
+            name: "Movies".to_string(),
+            collection_type: Some(CollectionType::Movies),
+            paths: vec!["/media/movies".to_string(), "/media/films".to_string()],
+            library_options: LibraryOptions {
+                preferred_metadata_language: Some("en".to_string()),
+                path_infos: vec![MediaPathInfo {
+                    path: "/media/movies".to_string(),
+                }],
+                ..Default::default()
+            },
+            refresh_library: true,
+        })
+        .await?;
+    client.remove_virtual_folder("Movies").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn virtual_folders_require_an_administrator() {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Library/VirtualFolders"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
+
+    assert!(matches!(
+        client.get_virtual_folders().await,
+        Err(JellyfinError::Forbidden(_))
+    ));
+}
+
+#[tokio::test]
+async fn add_and_remove_media_path() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Library/VirtualFolders/Paths"))
+        .and(body_json(
+            json!({ "Name": "Movies", "Path": "/mnt/disk2/movies" }),
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/Library/VirtualFolders/Paths"))
+        .and(query_param("Name", "Movies"))
+        .and(query_param("Path", "/mnt/disk2/movies"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.add_media_path("Movies", "/mnt/disk2/movies").await?;
+    client
+        .remove_media_path("Movies", "/mnt/disk2/movies")
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn media_path_rejects_empty_values() {
+    let (server, client) = init_mock_client().await;
+
+    assert!(matches!(
+        client.add_media_path("Movies", " ").await,
+        Err(JellyfinError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        client.remove_media_path("", "/mnt/disk2/movies").await,
+        Err(JellyfinError::InvalidInput(_))
+    ));
+
+    let requests = server.received_requests().await.unwrap_or_default();
+    assert!(requests
+        .iter()
+        .all(|request| !request.url.path().starts_with("/Library")));
+}
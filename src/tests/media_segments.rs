@@ -0,0 +1,67 @@
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    media_segments::{MediaSegmentQuery, MediaSegmentType},
+    tests::init_mock_client,
+};
+
+#[tokio::test]
+async fn get_media_segments_intro() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/MediaSegments/episode"))
+        .and(query_param("IncludeSegmentTypes", "Intro,Outro"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [
+                {
+                    "Id": "segment",
+                    "ItemId": "episode",
+                    "Type": "Intro",
+                    "StartTicks": 0,
+                    "EndTicks": 900000000
+                }
+            ],
+            "TotalRecordCount": 1,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let segments = client
+        .get_media_segments(
+            "episode",
+            &MediaSegmentQuery {
+                include_segment_types: vec![MediaSegmentType::Intro, MediaSegmentType::Outro],
+            },
+        )
+        .await?;
+
+    assert_eq!(segments.items[0].segment_type, MediaSegmentType::Intro);
+    assert_eq!(segments.items[0].end_ticks, 900_000_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_media_segments_unsupported_server() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/MediaSegments/episode"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let segments = client
+        .get_media_segments("episode", &MediaSegmentQuery::default())
+        .await?;
+
+    assert!(segments.items.is_empty());
+
+    Ok(())
+}
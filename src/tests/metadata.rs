@@ -0,0 +1,187 @@
+use std::time::Duration;
+
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    err::JellyfinError,
+    metadata::{
+        MetadataRefreshMode, RefreshOptions, RemoteSearchInfo, RemoteSearchQuery,
+        RemoteSearchResult,
+    },
+    tests::init_mock_client,
+};
+
+fn refreshed_item(date_last_refreshed: &str) -> serde_json::Value {
+    json!({
+        "Items": [{
+            "Id": "movie",
+            "Overview": "Refreshed",
+            "DateLastRefreshed": date_last_refreshed
+        }],
+        "TotalRecordCount": 1,
+        "StartIndex": 0
+    })
+}
+
+#[tokio::test]
+async fn refresh_item_and_wait_returns_the_refreshed_item() -> Result<(), Box<dyn std::error::Error>>
+{
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items"))
+        .and(query_param("Ids", "movie"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(refreshed_item("2026-01-01T00:00:00Z")),
+        )
+        .up_to_n_times(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/Items"))
+        .and(query_param("Ids", "movie"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(refreshed_item("2026-10-16T12:00:00Z")),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Items/movie/Refresh"))
+        .and(query_param("MetadataRefreshMode", "FullRefresh"))
+        .and(query_param("ReplaceAllMetadata", "true"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let options = RefreshOptions {
+        metadata_refresh_mode: Some(MetadataRefreshMode::FullRefresh),
+        replace_all_metadata: Some(true),
+        ..Default::default()
+    };
+    let item = client
+        .refresh_item_and_wait(
+            "movie",
+            &options,
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        )
+        .await?;
+
+    assert_eq!(
+        item.date_last_refreshed.as_deref(),
+        Some("2026-10-16T12:00:00Z")
+    );
+    assert_eq!(item.overview.as_deref(), Some("Refreshed"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn refresh_item_and_wait_times_out() {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(refreshed_item("2026-01-01T00:00:00Z")),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Items/movie/Refresh"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+
+    let result = client
+        .refresh_item_and_wait(
+            "movie",
+            &RefreshOptions::default(),
+            Duration::from_millis(10),
+            Duration::from_millis(50),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(JellyfinError::IoError(err)) if err.kind() == std::io::ErrorKind::TimedOut
+    ));
+}
+
+#[tokio::test]
+async fn remote_search_movie_and_apply() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    let result = json!({
+        "Name": "The Matrix",
+        "ProductionYear": 1999,
+        "ProviderIds": { "Tmdb": "603", "Imdb": "tt0133093" },
+        "ImageUrl": "https://image.tmdb.org/t/p/original/matrix.jpg",
+        "SearchProviderName": "TheMovieDb"
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/Items/RemoteSearch/Movie"))
+        .and(body_json(json!({
+            "SearchInfo": { "Name": "Matrix", "Year": 1999 },
+            "ItemId": "movie",
+            "IncludeDisabledProviders": false
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([result])))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Items/RemoteSearch/Apply/movie"))
+        .and(query_param("ReplaceAllImages", "true"))
+        .and(body_json(json!({
+            "Name": "The Matrix",
+            "ProductionYear": 1999,
+            "ProviderIds": { "Tmdb": "603", "Imdb": "tt0133093" },
+            "ImageUrl": "https://image.tmdb.org/t/p/original/matrix.jpg",
+            "SearchProviderName": "TheMovieDb",
+            "Overview": null,
+            "PremiereDate": null,
+            "IndexNumber": null,
+            "ParentIndexNumber": null
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let results = client
+        .remote_search_movie(&RemoteSearchQuery {
+            search_info: RemoteSearchInfo {
+                name: Some("Matrix".to_string()),
+                year: Some(1999),
+                ..Default::default()
+            },
+            item_id: Some("movie".to_string()),
+            ..Default::default()
+        })
+        .await?;
+
+    assert_eq!(
+        results,
+        [RemoteSearchResult {
+            name: Some("The Matrix".to_string()),
+            production_year: Some(1999),
+            provider_ids: [("Tmdb", "603"), ("Imdb", "tt0133093")]
+                .into_iter()
+                .map(|(key, id)| (key.to_string(), id.to_string()))
+                .collect(),
+            image_url: Some("https://image.tmdb.org/t/p/original/matrix.jpg".to_string()),
+            search_provider_name: Some("TheMovieDb".to_string()),
+            ..Default::default()
+        }]
+    );
+
+    client
+        .apply_remote_search_result("movie", &results[0], true)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,620 @@
+use std::time::Duration;
+
+use serde_json::json;
+use wiremock::matchers::{
+    body_json, body_partial_json, header, header_regex, method, path, query_param,
+};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    err::JellyfinError,
+    items::{ItemType, ItemsQuery},
+    tests::{init_mock_client, mock_user_auth, MOCK_TOKEN, MOCK_USER_ID},
+    user::{AuthKind, CreateUserRequest, User, UserAuth},
+    utils::ClientInfo,
+    AuthHeaderStyle, JellyfinClient,
+};
+
+fn token_header() -> String {
+    format!("Token=\"{}\"", MOCK_TOKEN)
+}
+
+#[tokio::test]
+async fn auth_user_name_sends_credentials() -> Result<(), Box<dyn std::error::Error>> {
+    let server = wiremock::MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Users/AuthenticateByName"))
+        .and(body_json(json!({ "Username": "test", "Pw": "secret" })))
+        .and(header_regex("X-Emby-Authorization", "Token=\"\"$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_user_auth()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = JellyfinClient::new(server.uri()).await?;
+    client.auth_user_name("test", "secret").await?;
+
+    assert!(client.auth_header()?.contains(&token_header()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_users_sends_token_and_filters() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Users"))
+        .and(query_param("is_hidden", "false"))
+        .and(query_param("is_disabled", "true"))
+        .and(header("Accept", "application/json"))
+        .and(header_regex("X-Emby-Authorization", &token_header()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![User {
+            name: "test".to_string(),
+            id: MOCK_USER_ID.to_string(),
+            ..Default::default()
+        }]))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let users = client.get_users(false, true).await?;
+
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].id, MOCK_USER_ID);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn current_user_is_cached_until_refreshed() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    assert_eq!(
+        client.current_user().map(|user| user.name),
+        Some("test".to_string())
+    );
+
+    Mock::given(method("GET"))
+        .and(path("/Users/Me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(User {
+            name: "renamed".to_string(),
+            id: MOCK_USER_ID.to_string(),
+            ..Default::default()
+        }))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.refresh_current_user().await?;
+
+    assert_eq!(
+        client.clone().current_user().map(|user| user.name),
+        Some("renamed".to_string())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_user_by_id_maps_http_errors() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Users/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("\"User not found\""))
+        .mount(&server)
+        .await;
+
+    match client.get_user_by_id("missing").await {
+        Err(JellyfinError::HttpRequestError {
+            status,
+            message,
+            body,
+            ..
+        }) => {
+            assert_eq!(status, 404);
+            assert_eq!(message, "User not found");
+            assert_eq!(body.as_deref(), Some("\"User not found\""));
+        }
+        other => panic!("Expected HttpRequestError, got {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_items_sends_query() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items"))
+        .and(query_param("ParentId", "boxset"))
+        .and(query_param("IncludeItemTypes", "Movie,Series"))
+        .and(query_param("EnableImageTypes", "Primary"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [{ "Id": "movie", "Name": "Movie", "Type": "Movie" }],
+            "TotalRecordCount": 1,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = client
+        .get_items(&ItemsQuery {
+            parent_id: Some("boxset".to_string()),
+            include_item_types: vec![ItemType::Movie, ItemType::Series],
+            ..Default::default()
+        })
+        .await?;
+
+    assert_eq!(result.total_record_count, 1);
+    assert_eq!(result.items[0].type_, Some(ItemType::Movie));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn auto_reauth_retries_once_after_401() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    client.enable_auto_reauth("test", "test");
+
+    Mock::given(method("GET"))
+        .and(path("/Users/Me"))
+        .respond_with(ResponseTemplate::new(401))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/Users/Me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_user_auth().user))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let user = client.get_user_by_auth().await?;
+
+    assert_eq!(user.id, MOCK_USER_ID);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_401s_log_in_once() -> Result<(), Box<dyn std::error::Error>> {
+    let server = wiremock::MockServer::start().await;
+    let client = JellyfinClient::new(server.uri()).await?;
+    client.set_auth(AuthKind::User(Box::new(UserAuth {
+        access_token: "stale-token".to_string(),
+        ..mock_user_auth()
+    })));
+    client.enable_auto_reauth("test", "test");
+
+    Mock::given(method("GET"))
+        .and(path("/Users/Me"))
+        .and(header_regex(
+            "X-Emby-Authorization",
+            "Token=\"stale-token\"",
+        ))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(3)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/Users/Me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_user_auth().user))
+        .expect(3)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Users/AuthenticateByName"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(mock_user_auth())
+                .set_delay(Duration::from_millis(50)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let (first, second, third) = tokio::join!(
+        client.get_user_by_auth(),
+        client.get_user_by_auth(),
+        client.get_user_by_auth()
+    );
+    for user in [first?, second?, third?] {
+        assert_eq!(user.id, MOCK_USER_ID);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn without_auto_reauth_401_is_returned() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Users/Me"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    match client.get_user_by_auth().await {
+        Err(JellyfinError::HttpRequestError { status, .. }) => assert_eq!(status, 401),
+        other => panic!("Expected HttpRequestError, got {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn patch_user_conf_only_changes_patched_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    let mut user = mock_user_auth().user;
+    user.configuration.enable_next_episode_auto_play = true;
+    user.configuration.subtitle_language_preference = "eng".to_string();
+
+    Mock::given(method("GET"))
+        .and(path(format!("/Users/{}", MOCK_USER_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&user))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/Users/{}/Configuration", MOCK_USER_ID)))
+        .and(body_partial_json(json!({
+            "EnableNextEpisodeAutoPlay": false,
+            "SubtitleLanguagePreference": "eng"
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let conf = client
+        .patch_user_conf(MOCK_USER_ID, |conf| {
+            conf.enable_next_episode_auto_play = false
+        })
+        .await?;
+
+    assert!(!conf.enable_next_episode_auto_play);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn patch_user_conf_keeps_unmodeled_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    let mut user = serde_json::to_value(mock_user_auth().user)?;
+    user["Configuration"]["CastReceiverId"] = json!("F007D354");
+
+    Mock::given(method("GET"))
+        .and(path(format!("/Users/{}", MOCK_USER_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&user))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/Users/{}/Configuration", MOCK_USER_ID)))
+        .and(body_partial_json(json!({
+            "EnableNextEpisodeAutoPlay": false,
+            "CastReceiverId": "F007D354"
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .patch_user_conf(MOCK_USER_ID, |conf| {
+            conf.enable_next_episode_auto_play = false
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn set_remote_access_keeps_unmodeled_policy_fields() -> Result<(), Box<dyn std::error::Error>>
+{
+    let (server, client) = init_mock_client().await;
+
+    let mut user = serde_json::to_value(mock_user_auth().user)?;
+    user["Policy"]["EnableCollectionManagement"] = json!(true);
+    user["Policy"]["AllowedTags"] = json!(["kids"]);
+
+    Mock::given(method("GET"))
+        .and(path(format!("/Users/{}", MOCK_USER_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&user))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/Users/{}/Policy", MOCK_USER_ID)))
+        .and(body_partial_json(json!({
+            "EnableRemoteAccess": false,
+            "EnableCollectionManagement": true,
+            "AllowedTags": ["kids"]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.set_remote_access(MOCK_USER_ID, false).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn set_downloading_keeps_other_policy_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    let mut user = mock_user_auth().user;
+    user.policy.enable_remote_access = true;
+    user.policy.enable_content_downloading = true;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/Users/{}", MOCK_USER_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&user))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/Users/{}/Policy", MOCK_USER_ID)))
+        .and(body_partial_json(json!({
+            "EnableContentDownloading": false,
+            "EnableRemoteAccess": true
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.set_downloading(MOCK_USER_ID, false).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn emby_token_header_style() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    let client = client.with_auth_header_style(AuthHeaderStyle::EmbyToken);
+
+    Mock::given(method("GET"))
+        .and(path("/Users/Me"))
+        .and(header("X-Emby-Token", MOCK_TOKEN))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_user_auth().user))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.get_user_by_auth().await?;
+
+    let requests = server.received_requests().await.unwrap_or_default();
+    let last = requests.last().expect("No request received");
+    assert!(!last.headers.contains_key("X-Emby-Authorization"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn locale_sends_accept_language() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    let client = client.with_locale("de-DE");
+
+    Mock::given(method("GET"))
+        .and(path("/Users/Me"))
+        .and(header("Accept-Language", "de-DE"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_user_auth().user))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.get_user_by_auth().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn proxy_receives_requests_with_credentials() -> Result<(), Box<dyn std::error::Error>> {
+    let proxy = wiremock::MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/System/Info/Public"))
+        // "user:secret" in base64.
+        .and(header("Proxy-Authorization", "Basic dXNlcjpzZWNyZXQ="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "Version": "10.9.11" })))
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    let client = JellyfinClient::new("http://jellyfin.invalid")
+        .await?
+        .with_proxy_auth(proxy.uri(), "user".to_string(), "secret".to_string())?;
+
+    client.get_public_system_info().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn proxy_url_is_validated() -> Result<(), Box<dyn std::error::Error>> {
+    let client = JellyfinClient::new("http://example.com").await?;
+
+    assert!(matches!(
+        client.clone().with_proxy("not a url"),
+        Err(JellyfinError::UrlParseError(_))
+    ));
+    assert!(matches!(
+        client.with_proxy("ftp://proxy.example.com"),
+        Err(JellyfinError::Unsupported(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn on_request_reports_errors_with_redacted_path() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    let metrics = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let recorded = metrics.clone();
+    client.on_request(Box::new(move |metric| {
+        recorded.lock().unwrap().push(metric)
+    }));
+
+    Mock::given(method("GET"))
+        .and(path("/Users/missing"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let request = client
+        .client
+        .get(format!("{}/Users/missing", server.uri()))
+        .query(&[("api_key", MOCK_TOKEN), ("Fields", "Path")]);
+    assert!(client.send_authed(request).await.is_err());
+
+    let metrics = metrics.lock().unwrap();
+    assert_eq!(metrics.len(), 1);
+    assert_eq!(metrics[0].method, reqwest::Method::GET);
+    assert_eq!(
+        metrics[0].path,
+        "/Users/missing?api_key=<redacted>&Fields=Path"
+    );
+    assert_eq!(metrics[0].status, Some(404));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_content_responses_are_handled() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/Users/{}", MOCK_USER_ID)))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/Users/New"))
+        .and(body_json(
+            json!({ "Name": "new-user", "Password": "password" }),
+        ))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/Users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![User {
+            name: "new-user".to_string(),
+            id: "new-user-id".to_string(),
+            ..Default::default()
+        }]))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .update_user(MOCK_USER_ID, mock_user_auth().user)
+        .await?;
+
+    let user = client.create_user("new-user", "password").await?;
+    assert_eq!(user.id, "new-user-id");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_user_with_request_without_password() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Users/New"))
+        .and(body_json(json!({ "Name": "guest" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(User {
+            name: "guest".to_string(),
+            id: "guest-id".to_string(),
+            ..Default::default()
+        }))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let user = client
+        .create_user_with(&CreateUserRequest {
+            name: "guest".to_string(),
+            password: None,
+        })
+        .await?;
+    assert_eq!(user.id, "guest-id");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn update_user_rejects_mismatched_id() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Users/4E4F-A1B2"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let user = User {
+        id: "4e4fa1b2".to_string(),
+        ..Default::default()
+    };
+    client.update_user("4E4F-A1B2", user.clone()).await?;
+
+    let result = client.update_user("someone-else", user).await;
+    assert!(matches!(result, Err(JellyfinError::InvalidInput(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_info_identifies_requests() -> Result<(), Box<dyn std::error::Error>> {
+    let server = wiremock::MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Users/AuthenticateByName"))
+        .and(header_regex(
+            "X-Emby-Authorization",
+            "Device=\"kiosk\", DeviceId=\"stable-id\"",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_user_auth()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = JellyfinClient::new(server.uri())
+        .await?
+        .with_client_info(ClientInfo {
+            device: "kiosk".to_string(),
+            device_id: "stable-id".to_string(),
+            ..ClientInfo::current()
+        });
+    client.auth_user_name("test", "secret").await?;
+
+    assert!(client.auth_header()?.contains("DeviceId=\"stable-id\""));
+    assert_eq!(
+        mock_user_auth().to_emby_header(client.client_info()),
+        client.auth_header()?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    err::JellyfinError,
+    music::{AudioTranscodeParams, TranscodingProtocol},
+    tests::{init_mock_client, MOCK_TOKEN},
+};
+
+#[tokio::test]
+async fn get_lyrics_success() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Audio/track/Lyrics"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Metadata": { "Artist": "Artist", "IsSynced": true },
+            "Lyrics": [
+                { "Text": "First line", "Start": 0 },
+                { "Text": "Second line", "Start": 52000000 }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let lyrics = client.get_lyrics("track").await?;
+
+    assert_eq!(lyrics.metadata.is_synced, Some(true));
+    assert_eq!(lyrics.lyrics.len(), 2);
+    assert_eq!(lyrics.lyrics[1].start, Some(52000000));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_lyrics_missing_is_not_found() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Audio/track/Lyrics"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    match client.get_lyrics("track").await {
+        Err(JellyfinError::NotFound(_)) => {}
+        other => panic!("Expected NotFound, got {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn audio_transcode_url_targets_universal_endpoint() -> Result<(), Box<dyn std::error::Error>>
+{
+    let (_server, client) = init_mock_client().await;
+
+    let params = AudioTranscodeParams {
+        container: vec!["mp3".to_string(), "flac".to_string()],
+        audio_codec: Some("aac".to_string()),
+        max_streaming_bitrate: Some(320_000),
+        transcoding_container: Some("ts".to_string()),
+        transcoding_protocol: Some(TranscodingProtocol::Hls),
+        play_session_id: Some("play-session".to_string()),
+        device_id: Some("device".to_string()),
+        ..Default::default()
+    };
+    let url = client.audio_transcode_url("track", &params)?;
+
+    assert_eq!(url.path(), "/Audio/track/universal");
+    assert_eq!(
+        url.query(),
+        Some(
+            format!(
+                "Container=mp3%2Cflac&AudioCodec=aac&MaxStreamingBitrate=320000\
+                 &TranscodingContainer=ts&TranscodingProtocol=hls&DeviceId=device\
+                 &PlaySessionId=play-session&api_key={}",
+                MOCK_TOKEN
+            )
+            .as_str()
+        )
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn audio_transcode_url_forces_transcoding_without_containers(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (_server, client) = init_mock_client().await;
+
+    let params = AudioTranscodeParams {
+        audio_codec: Some("mp3".to_string()),
+        ..Default::default()
+    };
+    let url = client.audio_transcode_url("track", &params)?;
+
+    assert_eq!(url.path(), "/Audio/track/stream.mp3");
+    let device_id = url
+        .query_pairs()
+        .find(|(key, _)| key == "DeviceId")
+        .map(|(_, value)| value.into_owned());
+    assert_eq!(device_id, Some(client.client_info().device_id.clone()));
+
+    Ok(())
+}
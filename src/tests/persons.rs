@@ -0,0 +1,40 @@
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    items::ItemType,
+    tests::{init_mock_client, MOCK_USER_ID},
+};
+
+#[tokio::test]
+async fn get_person_details() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Persons/Keanu%20Reeves"))
+        .and(query_param("UserId", MOCK_USER_ID))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Id": "person-id",
+            "Name": "Keanu Reeves",
+            "Type": "Person",
+            "Overview": "Canadian actor.",
+            "PremiereDate": "1964-09-02T00:00:00.0000000Z",
+            "ImageTags": { "Primary": "primary-tag" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let person = client.get_person("Keanu Reeves", MOCK_USER_ID).await?;
+
+    assert_eq!(person.type_, Some(ItemType::Person));
+    assert_eq!(person.overview.as_deref(), Some("Canadian actor."));
+    assert_eq!(
+        person.premiere_date.as_deref(),
+        Some("1964-09-02T00:00:00.0000000Z")
+    );
+    assert_eq!(person.end_date, None);
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::{
+    err::JellyfinError,
+    playback::{PlayQueueOptions, PlaybackDecision, PlaybackDecisionRequest},
+    tests::{init_mock_client, MOCK_USER_ID},
+};
+
+async fn mount_playback_info(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/Items/movie/PlaybackInfo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "MediaSources": [
+                { "Id": "hevc", "Container": "mkv", "SupportsDirectPlay": false, "SupportsTranscoding": true },
+                { "Id": "h264", "Container": "mp4", "SupportsDirectPlay": true }
+            ],
+            "PlaySessionId": "play-session"
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn direct_play_url_for_direct_playable_source() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+    mount_playback_info(&server).await;
+
+    let url = client.direct_play_url("movie", "h264").await?;
+
+    assert_eq!(url.path(), "/Videos/movie/stream");
+    let query: Vec<_> = url.query_pairs().collect();
+    assert!(query.contains(&("Static".into(), "true".into())));
+    assert!(query.contains(&("MediaSourceId".into(), "h264".into())));
+    assert!(query.contains(&("PlaySessionId".into(), "play-session".into())));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn direct_play_url_rejects_transcode_only_source() {
+    let (server, client) = init_mock_client().await;
+    mount_playback_info(&server).await;
+
+    let result = client.direct_play_url("movie", "hevc").await;
+    assert!(matches!(result, Err(JellyfinError::Unsupported(_))));
+
+    let result = client.direct_play_url("movie", "missing").await;
+    assert!(matches!(result, Err(JellyfinError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn build_play_queue_orders_intros_item_and_parts() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/movie"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Id": "movie",
+            "Type": "Movie",
+            "MediaType": "Video"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/movie/Intros"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [{ "Id": "trailer" }],
+            "TotalRecordCount": 1,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/Videos/movie/AdditionalParts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [{ "Id": "movie-part-2" }],
+            "TotalRecordCount": 1,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let queue = client
+        .build_play_queue(MOCK_USER_ID, "movie", &PlayQueueOptions::default())
+        .await?;
+
+    let ids: Vec<_> = queue.iter().map(|item| item.id.as_str()).collect();
+    assert_eq!(ids, ["trailer", "movie", "movie-part-2"]);
+
+    Ok(())
+}
+
+async fn decide(source: serde_json::Value) -> Result<PlaybackDecision, JellyfinError> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Items/movie/PlaybackInfo"))
+        .and(body_json(
+            json!({ "UserId": MOCK_USER_ID, "MaxStreamingBitrate": 8000000 }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "MediaSources": [source],
+            "PlaySessionId": "play-session"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = PlaybackDecisionRequest {
+        user_id: Some(MOCK_USER_ID.to_string()),
+        max_streaming_bitrate: Some(8_000_000),
+        ..Default::default()
+    };
+    client.decide_playback("movie", &request).await
+}
+
+#[tokio::test]
+async fn decide_playback_prefers_direct_play() -> Result<(), Box<dyn std::error::Error>> {
+    let decision = decide(json!({
+        "Id": "source",
+        "Container": "mkv",
+        "SupportsDirectPlay": true,
+        "SupportsDirectStream": true
+    }))
+    .await?;
+
+    let PlaybackDecision::DirectPlay { url } = decision else {
+        panic!("Expected direct play, got {:?}", decision);
+    };
+    assert_eq!(url.path(), "/Videos/movie/stream");
+    assert_eq!(
+        url.query(),
+        Some("Static=true&MediaSourceId=source&PlaySessionId=play-session&api_key=mock-token")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn decide_playback_direct_streams_into_container() -> Result<(), Box<dyn std::error::Error>> {
+    let decision = decide(json!({
+        "Id": "source",
+        "Container": "mkv",
+        "SupportsDirectStream": true,
+        "SupportsTranscoding": true
+    }))
+    .await?;
+
+    let PlaybackDecision::DirectStream { url } = decision else {
+        panic!("Expected direct stream, got {:?}", decision);
+    };
+    assert!(url.query().unwrap_or_default().contains("Container=mkv"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn decide_playback_transcodes_to_hls() -> Result<(), Box<dyn std::error::Error>> {
+    let decision = decide(json!({
+        "Id": "source",
+        "SupportsTranscoding": true,
+        "TranscodingUrl": "/videos/movie/master.m3u8?MediaSourceId=source&ApiKey=mock-token"
+    }))
+    .await?;
+
+    let PlaybackDecision::Transcode { hls_url } = decision else {
+        panic!("Expected transcoding, got {:?}", decision);
+    };
+    assert_eq!(hls_url.path(), "/videos/movie/master.m3u8");
+    assert_eq!(
+        hls_url.query(),
+        Some("MediaSourceId=source&ApiKey=mock-token")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn decide_playback_rejects_unplayable_source() {
+    let result = decide(json!({ "Id": "source" })).await;
+
+    assert!(matches!(result, Err(JellyfinError::Unsupported(_))));
+}
@@ -0,0 +1,102 @@
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::tests::init_mock_client;
+
+#[tokio::test]
+async fn get_configuration_pages_in_main_menu() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/web/ConfigurationPages"))
+        .and(query_param("EnableInMainMenu", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "Name": "OpenSubtitles",
+                "DisplayName": "Open Subtitles",
+                "PluginId": "4b9ed42f-5185-48b5-9803-6ff2989014c4",
+                "MenuSection": "server",
+                "EnableInMainMenu": true
+            }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let pages = client.get_configuration_pages(Some(true)).await?;
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].display_name.as_deref(), Some("Open Subtitles"));
+    assert_eq!(pages[0].menu_section.as_deref(), Some("server"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn install_package_then_restart_pending() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Packages/Installed/Open%20Subtitles"))
+        .and(query_param("Version", "20.0.0.0"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/System/Info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ServerName": "mock",
+            "Version": "10.9.11",
+            "HasPendingRestart": true,
+            "HasUpdateAvailable": false
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .install_package("Open Subtitles", Some("20.0.0.0"), None)
+        .await?;
+    let info = client.get_system_info().await?;
+
+    assert!(info.has_pending_restart);
+    assert!(!info.has_update_available);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_packages_with_versions() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Packages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "Name": "Open Subtitles",
+                "Guid": "4b9ed42f-5185-48b5-9803-6ff2989014c4",
+                "Category": "Subtitles",
+                "Overview": "Download subtitles for your media",
+                "Versions": [
+                    {
+                        "Version": "20.0.0.0",
+                        "TargetAbi": "10.9.0.0",
+                        "RepositoryUrl": "https://repo.jellyfin.org/releases/plugin/manifest-stable.json"
+                    }
+                ]
+            }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let packages = client.get_packages().await?;
+
+    assert_eq!(packages[0].category.as_deref(), Some("Subtitles"));
+    assert_eq!(packages[0].versions[0].version, "20.0.0.0");
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+//! Compile-time checks that the futures returned by the client can be spawned on a multi-threaded
+//! runtime, which requires them to be `Send`. The checks are never run.
+
+use std::future::Future;
+
+use crate::{
+    display_preferences::DisplayPreferences,
+    err::Result,
+    genres::GenresQuery,
+    images::RemoteImageQuery,
+    items::{ImageType, ItemsQuery, LatestItemsQuery, SuggestionsQuery},
+    media_segments::MediaSegmentQuery,
+    music::ArtistsQuery,
+    playback::{PlaybackProgressInfo, PlaybackSession, PlaybackStopInfo},
+    session::{ClientCapabilities, SessionsQuery},
+    tv::{EpisodesQuery, SeasonsQuery},
+    user::{User, UserConfiguration, UserPolicy},
+    videos::VideoStreamQuery,
+    JellyfinClient,
+};
+
+fn assert_send<F: Future + Send>(_: F) {}
+
+#[allow(dead_code)]
+fn client_futures_are_send(client: &JellyfinClient, id: &str) {
+    assert_send(JellyfinClient::new("http://example.com"));
+    assert_send(JellyfinClient::new_auth_name(
+        "http://example.com",
+        "user",
+        "password",
+    ));
+    assert_send(JellyfinClient::new_auth_std(
+        "http://example.com",
+        "id",
+        "password",
+    ));
+
+    assert_send(client.get_users(false, false));
+    assert_send(client.get_user_by_id(id));
+    assert_send(client.get_user_by_auth());
+    assert_send(client.refresh_current_user());
+    assert_send(client.create_user(id, id));
+    assert_send(client.update_user(id, User::default()));
+    assert_send(client.delete_user(id));
+    assert_send(client.auth_user_name(id, id));
+    assert_send(client.auth_user_std(id, id));
+    assert_send(client.update_user_conf(id, UserConfiguration::default()));
+    assert_send(client.patch_user_conf(id, |conf| conf.hide_played_in_latest = true));
+    assert_send(client.update_user_policy(id, UserPolicy::default()));
+    assert_send(client.patch_user_policy(id, |policy| policy.is_disabled = true));
+    assert_send(client.update_user_password(id, id));
+    assert_send(client.set_downloading(id, true));
+    assert_send(client.get_public_user_list());
+
+    assert_send(client.get_items(&ItemsQuery::default()));
+    assert_send(client.get_item(id, id));
+    assert_send(client.get_suggestions(id, &SuggestionsQuery::default()));
+    assert_send(client.get_latest_items(id, &LatestItemsQuery::default()));
+    assert_send(client.get_user_views(id));
+    assert_send(client.get_item_counts(None));
+    assert_send(client.update_item_metadata(id, id, |item| item.name = None));
+    assert_send(client.add_item_tag(id, id, id));
+    assert_send(client.set_item_likes(id, id, Some(true)));
+    assert_send(client.get_theme_media(id, id, true));
+
+    assert_send(client.get_genres(&GenresQuery::default()));
+    assert_send(client.get_person(id, id));
+    assert_send(client.get_studio(id, id));
+    assert_send(client.get_artists(&ArtistsQuery::default()));
+    assert_send(client.get_lyrics(id));
+    assert_send(client.get_seasons(id, &SeasonsQuery::default()));
+    assert_send(client.get_episodes(id, &EpisodesQuery::default()));
+    assert_send(client.get_media_segments(id, &MediaSegmentQuery::default()));
+    assert_send(client.get_trickplay_info(id));
+    assert_send(client.get_remote_images(id, &RemoteImageQuery::default()));
+    assert_send(client.download_remote_image(id, ImageType::Primary, id));
+    assert_send(client.get_display_preferences(id, id, id));
+    assert_send(client.update_display_preferences(id, id, id, &DisplayPreferences::default()));
+
+    assert_send(client.get_playback_info(id));
+    assert_send(client.direct_play_url(id, id));
+    assert_send(client.video_stream_url_respecting_policy(id, &VideoStreamQuery::default()));
+    assert_send(client.report_playback_start(&PlaybackProgressInfo::default()));
+    assert_send(client.report_playback_stopped(&PlaybackStopInfo::default()));
+    assert_send(PlaybackSession::start(
+        client.clone(),
+        PlaybackProgressInfo::default(),
+    ));
+    assert_send(client.download_item(id));
+    assert_send(client.download_item_range(id, 0, None));
+    assert_send(client.merge_versions(&[id]));
+
+    assert_send(client.get_sessions(&SessionsQuery::default()));
+    assert_send(client.get_now_playing(&[]));
+    assert_send(client.report_capabilities(&ClientCapabilities::default()));
+    assert_send(client.connect_websocket());
+    assert_send(client.playback_events());
+
+    assert_send(client.get_public_system_info());
+    assert_send(client.get_system_info());
+    assert_send(client.fetch_server_version());
+    assert_send(client.get_configuration_pages(None));
+    assert_send(client.get_packages());
+    assert_send(client.install_package(id, None, None));
+    assert_send(client.for_each_item_concurrent(
+        vec![id],
+        None,
+        |id| async move { Result::Ok(id) },
+    ));
+}
+
+#[allow(dead_code)]
+fn client_calls_can_be_spawned(client: JellyfinClient, user_id: String) {
+    tokio::spawn(async move { client.get_item(user_id, "item-id".to_string()).await });
+}
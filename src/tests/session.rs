@@ -0,0 +1,264 @@
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::err::JellyfinError;
+use crate::items::{ItemType, MediaType};
+use crate::session::{
+    ClientCapabilities, GeneralCommand, GeneralCommandType, PlayMethod, RepeatMode, SessionsQuery,
+};
+use crate::tests::init_mock_client;
+use crate::JellyfinClient;
+
+#[tokio::test]
+async fn get_now_playing_skips_idle_sessions() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Sessions"))
+        .and(query_param("ActiveWithinSeconds", "960"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "Id": "playing",
+                "UserName": "test",
+                "PlayState": {
+                    "PositionTicks": 1200000000,
+                    "IsPaused": false,
+                    "PlayMethod": "DirectPlay",
+                    "RepeatMode": "RepeatAll"
+                },
+                "NowPlayingItem": { "Id": "movie", "Name": "Movie", "Type": "Movie" }
+            },
+            {
+                "Id": "idle",
+                "UserName": "test",
+                "PlayState": {}
+            }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let now_playing = client.get_now_playing(&[]).await?;
+
+    assert_eq!(now_playing.len(), 1);
+    let (session, item) = &now_playing[0];
+    assert_eq!(session.id, "playing");
+    assert_eq!(session.play_state.position_ticks, Some(1200000000));
+    assert_eq!(session.play_state.play_method, Some(PlayMethod::DirectPlay));
+    assert_eq!(session.play_state.repeat_mode, RepeatMode::RepeatAll);
+    assert_eq!(item.id, "movie");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn report_capabilities_posts_body() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Sessions/Capabilities/Full"))
+        .and(body_json(json!({
+            "PlayableMediaTypes": ["Audio", "Video"],
+            "SupportedCommands": ["DisplayMessage"],
+            "SupportsMediaControl": true,
+            "SupportsPersistentIdentifier": false
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .report_capabilities(&ClientCapabilities {
+            playable_media_types: vec![MediaType::Audio, MediaType::Video],
+            supported_commands: vec!["DisplayMessage".to_string()],
+            supports_media_control: true,
+            supports_persistent_identifier: false,
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_now_playing_filters_item_types() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Sessions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "Id": "movie", "NowPlayingItem": { "Id": "movie-item", "Type": "Movie" } },
+            { "Id": "music", "NowPlayingItem": { "Id": "track", "Type": "Audio" } },
+            { "Id": "idle" }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let now_playing = client.get_now_playing(&[ItemType::Movie]).await?;
+
+    assert_eq!(now_playing.len(), 1);
+    assert_eq!(now_playing[0].0.id, "movie");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn report_viewing_sends_item() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Sessions/Viewing"))
+        .and(query_param("SessionId", "session"))
+        .and(query_param("ItemId", "movie"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.report_viewing("session", "movie").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn set_streams_send_general_commands() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Sessions/tv/Command"))
+        .and(body_json(json!({
+            "Name": "SetAudioStreamIndex",
+            "Arguments": { "Index": "2" }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Sessions/tv/Command"))
+        .and(body_json(json!({
+            "Name": "SetSubtitleStreamIndex",
+            "Arguments": { "Index": "-1" }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.set_audio_stream("tv", 2).await?;
+    client.set_subtitle_stream("tv", -1).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_general_command_sends_arguments() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Sessions/tv/Command"))
+        .and(body_json(json!({
+            "Name": "DisplayMessage",
+            "Arguments": { "Header": "Dinner", "Text": "Dinner is ready!", "TimeoutMs": "5000" }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Sessions/tv/Command"))
+        .and(body_json(json!({ "Name": "GoHome", "Arguments": {} })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let message = GeneralCommand::new(GeneralCommandType::DisplayMessage)
+        .with_argument("Header", "Dinner")
+        .with_argument("Text", "Dinner is ready!")
+        .with_argument("TimeoutMs", 5000);
+    client.send_general_command("tv", &message).await?;
+    client
+        .send_general_command("tv", &GeneralCommand::new(GeneralCommandType::GoHome))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sessions_stream_polls_and_survives_errors() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Sessions"))
+        .and(query_param("ActiveWithinSeconds", "60"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{ "Id": "first" }])))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/Sessions"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/Sessions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{ "Id": "second" }])))
+        .mount(&server)
+        .await;
+
+    let query = SessionsQuery {
+        active_within_seconds: Some(60),
+        ..Default::default()
+    };
+    let updates: Vec<_> = client
+        .sessions_stream(query, Duration::from_millis(10))?
+        .take(3)
+        .collect()
+        .await;
+
+    assert_eq!(
+        updates[0]
+            .as_ref()
+            .map(|sessions| sessions[0].id.as_str())
+            .ok(),
+        Some("first")
+    );
+    assert!(updates[1].is_err());
+    assert_eq!(
+        updates[2]
+            .as_ref()
+            .map(|sessions| sessions[0].id.as_str())
+            .ok(),
+        Some("second")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sessions_stream_rejects_zero_interval() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("Failed to build runtime");
+    let client = runtime
+        .block_on(JellyfinClient::new("http://example.com"))
+        .expect("Failed to create client");
+
+    // Called outside the runtime on purpose: creating the stream must not need one.
+
+    assert!(matches!(
+        client.sessions_stream(SessionsQuery::default(), Duration::ZERO),
+        Err(JellyfinError::InvalidInput(_))
+    ));
+    assert!(client
+        .sessions_stream(SessionsQuery::default(), Duration::from_secs(1))
+        .is_ok());
+}
@@ -0,0 +1,78 @@
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::{err::JellyfinError, JellyfinClient};
+
+#[tokio::test]
+async fn startup_wizard_sets_up_server() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Startup/Configuration"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "UICulture": "en-US",
+            "MetadataCountryCode": "US",
+            "PreferredMetadataLanguage": "en"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/Startup/User"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "Name": "root" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Startup/User"))
+        .and(body_json(json!({ "Name": "admin", "Password": "secret" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Startup/Complete"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = JellyfinClient::new(server.uri()).await?;
+    let config = client.startup_get_config().await?;
+    client.startup_set_user("admin", "secret").await?;
+    client.startup_complete().await?;
+
+    assert_eq!(config.ui_culture.as_deref(), Some("en-US"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn startup_wizard_is_forbidden_once_completed() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Startup/Configuration"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Startup/Complete"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
+
+    let client = JellyfinClient::new(server.uri()).await?;
+
+    assert!(matches!(
+        client.startup_get_config().await,
+        Err(JellyfinError::Forbidden(_))
+    ));
+    assert!(matches!(
+        client.startup_complete().await,
+        Err(JellyfinError::Forbidden(_))
+    ));
+
+    Ok(())
+}
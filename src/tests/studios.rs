@@ -0,0 +1,35 @@
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    items::ItemType,
+    tests::{init_mock_client, MOCK_USER_ID},
+};
+
+#[tokio::test]
+async fn get_studio_details() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Studios/Studio%20Ghibli%2FTokuma"))
+        .and(query_param("UserId", MOCK_USER_ID))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Id": "studio-id",
+            "Name": "Studio Ghibli/Tokuma",
+            "Type": "Studio",
+            "ImageTags": { "Thumb": "thumb-tag" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let studio = client
+        .get_studio("Studio Ghibli/Tokuma", MOCK_USER_ID)
+        .await?;
+
+    assert_eq!(studio.type_, Some(ItemType::Studio));
+    assert_eq!(studio.name.as_deref(), Some("Studio Ghibli/Tokuma"));
+
+    Ok(())
+}
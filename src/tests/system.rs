@@ -0,0 +1,207 @@
+use futures_util::TryStreamExt;
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::{
+    err::JellyfinError,
+    system::{EndpointInfo, LogFile, Version},
+    tests::{init_mock_client, MOCK_USER_ID},
+    JellyfinClient,
+};
+
+#[test]
+fn version_parse() {
+    assert_eq!(Version::parse("10.9.11"), Some(Version::new(10, 9, 11)));
+    assert_eq!(Version::parse("10.10"), Some(Version::new(10, 10, 0)));
+    assert_eq!(Version::parse("10.11.0-rc1"), Some(Version::new(10, 11, 0)));
+    assert_eq!(Version::parse("unstable"), None);
+    assert!(Version::new(10, 10, 0) > Version::new(10, 9, 11));
+}
+
+#[tokio::test]
+async fn server_version_is_stored_after_first_fetch() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/System/Info/Public"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ServerName": "mock",
+            "Version": "10.9.11",
+            "Id": "mock-server-id"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = JellyfinClient::new(server.uri()).await?;
+    assert_eq!(client.server_version(), None);
+
+    let version = client.fetch_server_version().await?;
+    assert_eq!(version, Some(Version::new(10, 9, 11)));
+
+    // The second call is answered from the stored version; the mock expects a single request.
+    assert_eq!(client.fetch_server_version().await?, version);
+    assert_eq!(client.clone().server_version(), version);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unparseable_server_version_is_fetched_once() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/System/Info/Public"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "Version": "unstable" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/UserViews"))
+        .and(query_param("userId", MOCK_USER_ID))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [],
+            "TotalRecordCount": 0,
+            "StartIndex": 0
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    client.get_user_views(MOCK_USER_ID).await?;
+    client.get_user_views(MOCK_USER_ID).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_server_version_fetch_is_retried() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/System/Info/Public"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/System/Info/Public"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "Version": "10.8.13" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // The first call cannot tell the version and falls back to the 10.9 path.
+    Mock::given(method("GET"))
+        .and(path("/UserViews"))
+        .and(query_param("userId", MOCK_USER_ID))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [],
+            "TotalRecordCount": 0,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/Users/{}/Views", MOCK_USER_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [],
+            "TotalRecordCount": 0,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.get_user_views(MOCK_USER_ID).await?;
+    client.get_user_views(MOCK_USER_ID).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn log_files_are_listed_and_downloaded() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/System/Logs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "Name": "log_20261016.log",
+            "DateCreated": "2026-10-16T00:00:00Z",
+            "DateModified": "2026-10-16T12:00:00Z",
+            "Size": 11
+        }])))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/System/Logs/Log"))
+        .and(query_param("Name", "log_20261016.log"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[INF] Ready"))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let logs = client.get_log_files().await?;
+    assert_eq!(
+        logs,
+        vec![LogFile {
+            name: "log_20261016.log".to_string(),
+            date_created: Some("2026-10-16T00:00:00Z".to_string()),
+            date_modified: Some("2026-10-16T12:00:00Z".to_string()),
+            size: 11,
+        }]
+    );
+
+    assert_eq!(client.download_log(&logs[0].name).await?, "[INF] Ready");
+
+    let chunks: Vec<_> = client
+        .download_log_stream(&logs[0].name)
+        .await?
+        .try_collect()
+        .await?;
+    assert_eq!(chunks.concat(), b"[INF] Ready");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn log_files_require_an_administrator() {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/System/Logs"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
+
+    assert!(matches!(
+        client.get_log_files().await,
+        Err(JellyfinError::Forbidden(_))
+    ));
+}
+
+#[tokio::test]
+async fn endpoint_info_is_parsed() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/System/Endpoint"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "IsLocal": false,
+            "IsInNetwork": true
+        })))
+        .mount(&server)
+        .await;
+
+    assert_eq!(
+        client.get_endpoint_info().await?,
+        EndpointInfo {
+            is_local: false,
+            is_in_network: true,
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::tests::{init_mock_client, MOCK_TOKEN};
+
+#[tokio::test]
+async fn get_trickplay_info_by_width() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items"))
+        .and(query_param("Ids", "movie"))
+        .and(query_param("Fields", "Trickplay"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [
+                {
+                    "Id": "movie",
+                    "Trickplay": {
+                        "movie": {
+                            "320": {
+                                "Width": 320,
+                                "Height": 180,
+                                "TileWidth": 10,
+                                "TileHeight": 10,
+                                "ThumbnailCount": 720,
+                                "Interval": 10000,
+                                "Bandwidth": 4000
+                            }
+                        }
+                    }
+                }
+            ],
+            "TotalRecordCount": 1,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let info = client.get_trickplay_info("movie").await?;
+
+    assert_eq!(info[&320].tile_width, 10);
+    assert_eq!(info[&320].thumbnail_count, 720);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn trickplay_tile_url_carries_token() -> Result<(), Box<dyn std::error::Error>> {
+    let (_server, client) = init_mock_client().await;
+
+    let url = client.trickplay_tile_url("movie", 320, 3)?;
+
+    assert_eq!(url.path(), "/Videos/movie/Trickplay/320/3.jpg");
+    assert_eq!(
+        url.query(),
+        Some(format!("api_key={}", MOCK_TOKEN).as_str())
+    );
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    items::ItemFields,
+    playback::MediaStreamType,
+    tests::init_mock_client,
+    tv::{EpisodesQuery, SeasonsQuery},
+};
+
+#[tokio::test]
+async fn get_episodes_next_episode() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Shows/series/Episodes"))
+        .and(query_param("AdjacentTo", "current"))
+        .and(query_param("IsMissing", "false"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [
+                { "Id": "previous", "IndexNumber": 1 },
+                { "Id": "current", "IndexNumber": 2 },
+                { "Id": "next", "IndexNumber": 3 }
+            ],
+            "TotalRecordCount": 3,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let episodes = client
+        .get_episodes(
+            "series",
+            &EpisodesQuery {
+                adjacent_to: Some("current".to_string()),
+                is_missing: Some(false),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    assert_eq!(episodes.items.len(), 3);
+    assert_eq!(episodes.items[2].id, "next");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_seasons_excluding_specials() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Shows/series/Seasons"))
+        .and(query_param("IsSpecialSeason", "false"))
+        .and(query_param("Fields", "ChildCount"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [
+                { "Id": "season-1", "Type": "Season", "IndexNumber": 1, "ChildCount": 10 },
+                { "Id": "season-2", "Type": "Season", "IndexNumber": 2, "ChildCount": 8 }
+            ],
+            "TotalRecordCount": 2,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let seasons = client
+        .get_seasons(
+            "series",
+            &SeasonsQuery {
+                exclude_specials: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    assert_eq!(seasons.items.len(), 2);
+    assert_eq!(seasons.items[0].index_number, Some(1));
+    assert_eq!(seasons.items[1].child_count, Some(8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_episodes_with_media_streams() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Shows/series/Episodes"))
+        .and(query_param("Fields", "MediaStreams,MediaSources"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [
+                {
+                    "Id": "episode",
+                    "Type": "Episode",
+                    "MediaStreams": [
+                        { "Type": "Video", "Index": 0, "Codec": "h264", "Width": 1920, "Height": 1080 },
+                        { "Type": "Audio", "Index": 1, "Codec": "aac", "Language": "eng", "Channels": 2, "IsDefault": true },
+                        { "Type": "Subtitle", "Index": 2, "Codec": "subrip", "Language": "fre", "IsExternal": true, "IsTextSubtitleStream": true }
+                    ],
+                    "MediaSources": [
+                        {
+                            "Id": "episode",
+                            "Container": "mkv",
+                            "SupportsDirectPlay": true,
+                            "DefaultAudioStreamIndex": 1,
+                            "MediaStreams": [{ "Type": "Video", "Index": 0 }]
+                        }
+                    ]
+                }
+            ],
+            "TotalRecordCount": 1,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let episodes = client
+        .get_episodes(
+            "series",
+            &EpisodesQuery {
+                fields: vec![ItemFields::MediaStreams, ItemFields::MediaSources],
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    let episode = &episodes.items[0];
+    assert_eq!(episode.media_streams.len(), 3);
+    assert_eq!(episode.media_streams[1].type_, MediaStreamType::Audio);
+    assert_eq!(episode.media_streams[1].language.as_deref(), Some("eng"));
+    assert!(episode.media_streams[2].is_external);
+    assert_eq!(episode.media_sources[0].default_audio_stream_index, Some(1));
+    assert_eq!(episode.media_sources[0].media_streams.len(), 1);
+
+    let round_trip: crate::items::BaseItemDto =
+        serde_json::from_value(serde_json::to_value(episode)?)?;
+    assert_eq!(&round_trip, episode);
+
+    Ok(())
+}
@@ -112,7 +112,7 @@ async fn delete_user_success() -> Result<(), Box<dyn std::error::Error>> {
                 assert_eq!(status, 404, "Expected HTTP 404 error for user not found.");
                 assert_eq!(
                     message,
-                    "\"User not found\"".to_string(),
+                    "User not found".to_string(),
                     "Expected message `User not found`"
                 )
             }
@@ -177,12 +177,12 @@ async fn get_user_by_id_non_existing_id() -> Result<(), Box<dyn std::error::Erro
 async fn auth_user_name_success() -> Result<(), Box<dyn std::error::Error>> {
     let (server_url, username, password) = get_config();
 
-    let mut client = JellyfinClient::new(server_url).await?;
+    let client = JellyfinClient::new(server_url).await?;
     client.auth_user_name(username, password).await?;
 
     // Assert that the client's `auth` field is now set
     assert!(
-        client.auth.is_some(),
+        client.auth.read().unwrap().is_some(),
         "Client auth should be set after successful authentication"
     );
 
@@ -196,7 +196,7 @@ async fn auth_user_name() -> Result<(), Box<dyn std::error::Error>> {
     let username = "invalid_user";
     let password = "wrong_password";
 
-    let mut client = JellyfinClient::new(server_url).await?;
+    let client = JellyfinClient::new(server_url).await?;
 
     // Attempt to authenticate with incorrect credentials
     let result = client.auth_user_name(username, password).await;
@@ -0,0 +1,71 @@
+use crate::utils::{build_emby_auth_header, ClientInfo};
+
+fn client_info(device: &str) -> ClientInfo {
+    ClientInfo {
+        client: "jellyfin-rs".to_string(),
+        device: device.to_string(),
+        device_id: "device-id".to_string(),
+        version: "1".to_string(),
+    }
+}
+
+#[test]
+fn emby_auth_header_format() {
+    assert_eq!(
+        build_emby_auth_header(&client_info("laptop"), Some("token")),
+        "MediaBrowser Client=\"jellyfin-rs\", Device=\"laptop\", DeviceId=\"device-id\", Version=1, Token=\"token\""
+    );
+}
+
+#[test]
+fn emby_auth_header_anonymous_has_empty_token() {
+    let header = build_emby_auth_header(&client_info("laptop"), None);
+
+    assert!(header.ends_with(", Token=\"\""));
+}
+
+#[test]
+fn emby_auth_header_sanitizes_device_name() {
+    let header = build_emby_auth_header(&client_info("Bob's \"Mac\", 2\\3"), Some("token"));
+
+    assert!(header.contains("Device=\"Bob's__Mac___2_3\""));
+    assert_eq!(header.matches('"').count(), 8);
+}
+
+#[test]
+fn current_client_info_is_header_safe() {
+    let info = ClientInfo::current();
+
+    assert!(!info.device.contains(['"', ',', ' ']));
+    assert_eq!(info.device_id, format!("{:x}", md5::compute(&info.device)));
+}
+
+#[test]
+fn persistent_client_info_keeps_device_id() {
+    let app_dir = std::env::temp_dir().join(format!("jellyfin-rs-{}", std::process::id()));
+
+    let first = ClientInfo::persistent(&app_dir);
+    let second = ClientInfo::persistent(&app_dir);
+    std::fs::remove_dir_all(&app_dir).ok();
+
+    assert_eq!(first.device_id.len(), 36);
+    assert_eq!(first.device_id.as_bytes()[14], b'4');
+    assert_eq!(first.device_id, second.device_id);
+    assert_eq!(first.device, ClientInfo::current().device);
+}
+
+#[test]
+fn persistent_client_info_falls_back_to_memory() -> std::io::Result<()> {
+    // A file where the directory should be makes the device ID impossible to store.
+    let app_dir = std::env::temp_dir().join(format!("jellyfin-rs-file-{}", std::process::id()));
+    std::fs::write(&app_dir, "")?;
+
+    let first = ClientInfo::persistent(&app_dir);
+    let second = ClientInfo::persistent(&app_dir);
+    std::fs::remove_file(&app_dir)?;
+
+    assert_eq!(first.device_id.len(), 36);
+    assert_ne!(first.device_id, second.device_id);
+
+    Ok(())
+}
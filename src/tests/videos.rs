@@ -0,0 +1,147 @@
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use crate::{
+    err::JellyfinError,
+    tests::{init_mock_client, mock_user_auth, MOCK_TOKEN},
+    videos::VideoStreamQuery,
+    JellyfinClient,
+};
+
+#[tokio::test]
+async fn get_additional_parts_in_order() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Videos/movie/AdditionalParts"))
+        .and(query_param("UserId", "user"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Items": [
+                { "Id": "part-2", "Name": "Movie - CD2" },
+                { "Id": "part-3", "Name": "Movie - CD3" }
+            ],
+            "TotalRecordCount": 2,
+            "StartIndex": 0
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let parts = client.get_additional_parts("movie", "user").await?;
+
+    let ids: Vec<_> = parts.items.iter().map(|item| item.id.as_str()).collect();
+    assert_eq!(ids, ["part-2", "part-3"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn merge_versions_sends_ids() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/Videos/MergeVersions"))
+        .and(query_param("Ids", "hd,uhd"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.merge_versions(&["hd", "uhd"]).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn split_versions_forbidden_for_non_admins() {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/Videos/movie/AlternateSources"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
+
+    let result = client.split_versions("movie").await;
+
+    assert!(matches!(result, Err(JellyfinError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn video_stream_url_clamps_to_policy_limit() -> Result<(), Box<dyn std::error::Error>> {
+    let server = wiremock::MockServer::start().await;
+
+    let mut auth = mock_user_auth();
+    auth.user.policy.remote_client_bitrate_limit = 8_000_000;
+    Mock::given(method("POST"))
+        .and(path("/Users/AuthenticateByName"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&auth))
+        .mount(&server)
+        .await;
+    // The limit comes from the user received when logging in.
+    Mock::given(method("GET"))
+        .and(path("/Users/Me"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = JellyfinClient::new_auth_name(server.uri().as_str(), "test", "test").await?;
+
+    let query = VideoStreamQuery {
+        static_: Some(false),
+        max_streaming_bitrate: Some(20_000_000),
+        ..Default::default()
+    };
+    let url = client
+        .video_stream_url_respecting_policy("movie", &query)
+        .await?;
+
+    assert_eq!(url.path(), "/Videos/movie/stream");
+    assert_eq!(
+        url.query(),
+        Some(
+            format!(
+                "Static=false&MaxStreamingBitrate=8000000&api_key={}",
+                MOCK_TOKEN
+            )
+            .as_str()
+        )
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn media_source_stream_url_targets_version() -> Result<(), Box<dyn std::error::Error>> {
+    let (server, client) = init_mock_client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/Items/movie/PlaybackInfo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "MediaSources": [
+                { "Id": "movie-1080p", "Name": "1080p" },
+                { "Id": "movie-4k", "Name": "4K" }
+            ],
+            "PlaySessionId": "play-session"
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let url = client
+        .media_source_stream_url("movie", "movie-4k", &VideoStreamQuery::default())
+        .await?;
+
+    let query: Vec<_> = url.query_pairs().collect();
+    assert!(query.contains(&("MediaSourceId".into(), "movie-4k".into())));
+    assert!(query.contains(&("PlaySessionId".into(), "play-session".into())));
+
+    let result = client
+        .media_source_stream_url("movie", "movie-720p", &VideoStreamQuery::default())
+        .await;
+    assert!(matches!(result, Err(JellyfinError::NotFound(_))));
+
+    Ok(())
+}
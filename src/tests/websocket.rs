@@ -0,0 +1,250 @@
+use futures_util::{SinkExt, StreamExt};
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::{
+    err::JellyfinError,
+    tests::mock_authed_client,
+    websocket::{PlaybackEventKind, ReconnectPolicy, WebSocketEvent, WebSocketMessage},
+};
+
+fn sessions_message(sessions: Value) -> Message {
+    Message::Text(json!({ "MessageType": "Sessions", "Data": sessions }).to_string())
+}
+
+fn playing(position_ticks: i64, is_paused: bool) -> Value {
+    json!([{
+        "Id": "session",
+        "PlayState": { "PositionTicks": position_ticks, "IsPaused": is_paused },
+        "NowPlayingItem": { "Id": "movie" }
+    }])
+}
+
+#[tokio::test]
+async fn playback_events_from_session_updates() -> Result<(), Box<dyn std::error::Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.expect("No connection");
+        let mut socket = tokio_tungstenite::accept_async(stream)
+            .await
+            .expect("Handshake failed");
+
+        let Some(Ok(Message::Text(subscription))) = socket.next().await else {
+            panic!("No subscription received");
+        };
+        let subscription: WebSocketMessage =
+            serde_json::from_str(&subscription).expect("Invalid subscription");
+        assert_eq!(subscription.message_type, "SessionsStart");
+
+        for message in [
+            sessions_message(playing(0, false)),
+            sessions_message(playing(10_000_000, false)),
+            Message::Ping(Vec::new()),
+            sessions_message(playing(10_000_000, true)),
+            sessions_message(json!([{ "Id": "session" }])),
+        ] {
+            socket.send(message).await.expect("Failed to send");
+        }
+        socket.close(None).await.ok();
+        while socket.next().await.is_some() {}
+    });
+
+    let client = mock_authed_client(&url).await;
+    let events: Vec<_> = client
+        .playback_events()
+        .await?
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<_, _>>()?;
+    server.await?;
+
+    let kinds: Vec<_> = events.iter().map(|event| event.event_kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            PlaybackEventKind::Start,
+            PlaybackEventKind::Progress,
+            PlaybackEventKind::Pause,
+            PlaybackEventKind::Stop,
+        ]
+    );
+    assert_eq!(events[1].position_ticks, Some(10_000_000));
+    assert!(events.iter().all(|event| event.item_id == "movie"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reconnects_after_connection_lost() -> Result<(), Box<dyn std::error::Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+
+    let server = tokio::spawn(async move {
+        for message_type in ["First", "Second"] {
+            let (stream, _) = listener.accept().await.expect("No connection");
+            let mut socket = tokio_tungstenite::accept_async(stream)
+                .await
+                .expect("Handshake failed");
+
+            let Some(Ok(Message::Text(subscription))) = socket.next().await else {
+                panic!("No subscription received");
+            };
+            let subscription: WebSocketMessage =
+                serde_json::from_str(&subscription).expect("Invalid subscription");
+            assert_eq!(subscription.message_type, "SessionsStart");
+
+            let message = json!({ "MessageType": message_type }).to_string();
+            socket
+                .send(Message::Text(message))
+                .await
+                .expect("Failed to send");
+            // Drop the connection without a close handshake, as a restarting server would.
+        }
+    });
+
+    let client = mock_authed_client(&url).await;
+    let subscription = WebSocketMessage {
+        message_type: "SessionsStart".to_string(),
+        data: Value::String("0,1000".to_string()),
+        message_id: None,
+    };
+    let policy = ReconnectPolicy {
+        initial_delay: Duration::from_millis(10),
+        ..Default::default()
+    };
+    let events: Vec<_> = client
+        .connect_websocket_reconnecting(vec![subscription], policy)
+        .await?
+        .take(4)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<_, _>>()?;
+    server.await?;
+
+    let message = |message_type: &str| {
+        WebSocketEvent::Message(WebSocketMessage {
+            message_type: message_type.to_string(),
+            ..Default::default()
+        })
+    };
+    assert_eq!(
+        events,
+        [
+            message("First"),
+            WebSocketEvent::Reconnecting {
+                attempt: 1,
+                delay: Duration::from_millis(10),
+            },
+            WebSocketEvent::Reconnected,
+            message("Second"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn does_not_reconnect_on_invalid_message() -> Result<(), Box<dyn std::error::Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.expect("No connection");
+        let mut socket = tokio_tungstenite::accept_async(stream)
+            .await
+            .expect("Handshake failed");
+
+        for message in [
+            Message::Text("not json".to_string()),
+            Message::Text(json!({ "MessageType": "Next" }).to_string()),
+        ] {
+            socket.send(message).await.expect("Failed to send");
+        }
+        socket.close(None).await.ok();
+        while socket.next().await.is_some() {}
+    });
+
+    let client = mock_authed_client(&url).await;
+    let mut events = Box::pin(
+        client
+            .connect_websocket_reconnecting(Vec::new(), ReconnectPolicy::default())
+            .await?,
+    );
+
+    assert!(matches!(
+        events.next().await,
+        Some(Err(JellyfinError::JsonError(_)))
+    ));
+    match events.next().await {
+        Some(Ok(WebSocketEvent::Message(message))) => assert_eq!(message.message_type, "Next"),
+        other => panic!("Expected the next message, got {:?}", other),
+    }
+    drop(events);
+    server.await?;
+
+    Ok(())
+}
+
+#[test]
+fn reconnect_delay_backs_off_up_to_max() {
+    let policy = ReconnectPolicy::default();
+
+    assert_eq!(policy.delay(1), Duration::from_secs(1));
+    assert_eq!(policy.delay(3), Duration::from_secs(4));
+    assert_eq!(policy.delay(10), Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn answers_force_keep_alive() -> Result<(), Box<dyn std::error::Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.expect("No connection");
+        let mut socket = tokio_tungstenite::accept_async(stream)
+            .await
+            .expect("Handshake failed");
+
+        let message = json!({ "MessageType": "ForceKeepAlive", "Data": 0.2 }).to_string();
+        socket
+            .send(Message::Text(message))
+            .await
+            .expect("Failed to send");
+
+        // One reply right away, then one per interval.
+        for _ in 0..2 {
+            let Some(Ok(Message::Text(keep_alive))) = socket.next().await else {
+                panic!("No keep alive received");
+            };
+            let keep_alive: WebSocketMessage =
+                serde_json::from_str(&keep_alive).expect("Invalid keep alive");
+            assert_eq!(keep_alive.message_type, "KeepAlive");
+        }
+
+        socket.close(None).await.ok();
+        while socket.next().await.is_some() {}
+    });
+
+    let client = mock_authed_client(&url).await;
+    let mut socket = client.connect_websocket().await?;
+    assert_eq!(socket.keep_alive_interval(), None);
+
+    let message = socket.next().await.expect("No message")?;
+    assert_eq!(message.message_type, "ForceKeepAlive");
+    assert_eq!(
+        socket.keep_alive_interval(),
+        Some(Duration::from_millis(100))
+    );
+
+    while socket.next().await.transpose()?.is_some() {}
+    server.await?;
+
+    Ok(())
+}
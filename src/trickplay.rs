@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use url::Url;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::QueryResult;
+use crate::JellyfinClient;
+
+/// How the trickplay images of an item are laid out for one thumbnail width.
+///
+/// Thumbnails are packed into tile images of `tile_width` by `tile_height` thumbnails, so the
+/// thumbnail for a position is thumbnail `position_ms / interval`, found in tile
+/// `thumbnail / (tile_width * tile_height)`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TrickplayInfo {
+    /// The width of a single thumbnail, in pixels.
+    pub width: u32,
+    /// The height of a single thumbnail, in pixels.
+    pub height: u32,
+    /// The number of thumbnails per row of a tile image.
+    pub tile_width: u32,
+    /// The number of thumbnails per column of a tile image.
+    pub tile_height: u32,
+    pub thumbnail_count: u32,
+    /// The time between two thumbnails, in milliseconds.
+    pub interval: u32,
+    /// The peak bandwidth of the tile images, in bits per second.
+    pub bandwidth: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrickplayItemQuery {
+    ids: String,
+    fields: &'static str,
+}
+
+/// The part of an item that holds its trickplay information, keyed by media source and then by
+/// thumbnail width.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrickplayItem {
+    #[serde(default)]
+    trickplay: HashMap<String, HashMap<u32, TrickplayInfo>>,
+}
+
+impl JellyfinClient {
+    /// Gets the trickplay image layouts of an item, used for seek bar thumbnails.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the video item.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a map of thumbnail widths to their `TrickplayInfo` if successful, which is
+    /// empty when no trickplay images were generated for the item, `JellyfinError::NotFound` if
+    /// the item does not exist, or another `JellyfinError` otherwise.
+    pub async fn get_trickplay_info<T: Into<String>>(
+        &self,
+        item_id: T,
+    ) -> Result<HashMap<u32, TrickplayInfo>> {
+        let item_id = item_id.into();
+        let endpoint_url = self.url.join("/Items").expect("Failed to join URL");
+
+        let query = TrickplayItemQuery {
+            ids: item_id.clone(),
+            fields: "Trickplay",
+        };
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(&query))
+            .await?;
+
+        let result: QueryResult<TrickplayItem> =
+            response.json().await.map_err(JellyfinError::NetworkError)?;
+
+        let mut trickplay = result
+            .items
+            .into_iter()
+            .next()
+            .ok_or_else(|| JellyfinError::not_found(format!("Item {}", item_id)))?
+            .trickplay;
+
+        // The default media source of an item shares its ID.
+        Ok(trickplay
+            .remove(&item_id)
+            .or_else(|| trickplay.into_values().next())
+            .unwrap_or_default())
+    }
+
+    /// Builds the URL of a trickplay tile image.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the video item.
+    /// * `width` - The thumbnail width, one of the keys returned by `get_trickplay_info`.
+    /// * `index` - The index of the tile image.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the tile image `Url` if successful, or `JellyfinError::AuthNotFound` if the client is not authenticated.
+    pub fn trickplay_tile_url<T: Into<String>>(
+        &self,
+        item_id: T,
+        width: u32,
+        index: u32,
+    ) -> Result<Url> {
+        self.authed_url(
+            &format!(
+                "/Videos/{}/Trickplay/{}/{}.jpg",
+                item_id.into(),
+                width,
+                index
+            ),
+            &(),
+        )
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/trickplay.rs"]
+mod tests;
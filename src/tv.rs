@@ -0,0 +1,125 @@
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::{BaseItemDto, ItemFields, QueryResult};
+use crate::serde::comma_separated;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EpisodesQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season_id: Option<String>,
+    /// The season number, used when the season id is not known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<i64>,
+    /// Filters on episodes that are known from metadata but have no file. Only takes effect when
+    /// the user's `display_missing_episodes` setting is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_missing: Option<bool>,
+    /// Filters on missing episodes that have not aired yet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_virtual_unaired: Option<bool>,
+    /// Only returns the episodes directly before and after the given episode, which is how the
+    /// previous and next episode are looked up.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjacent_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Optional fields to return for each episode, such as `ItemFields::MediaStreams` to show
+    /// audio and subtitle indicators without fetching every episode.
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<ItemFields>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SeasonsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// Leaves out the specials season (season `0`), which many UIs show separately or hide.
+    #[serde(skip)]
+    pub exclude_specials: bool,
+    /// Filters on seasons that are known from metadata but have no episode files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_missing: Option<bool>,
+    /// Optional fields to return for each season. `ItemFields::ChildCount` is always requested.
+    #[serde(with = "comma_separated", skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<ItemFields>,
+}
+
+impl JellyfinClient {
+    /// Gets the seasons of a series.
+    ///
+    /// Each season's `index_number` is its season number, `0` being the specials season, and its
+    /// `child_count` is the number of episodes it contains.
+    ///
+    /// # Arguments
+    ///
+    /// * `series_id` - The ID of the series.
+    /// * `query` - Filters for the season list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_seasons<T: Into<String>>(
+        &self,
+        series_id: T,
+        query: &SeasonsQuery,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Shows/{}/Seasons", series_id.into()))
+            .expect("Failed to join URL");
+
+        let mut query = query.clone();
+        if !query.fields.contains(&ItemFields::ChildCount) {
+            query.fields.push(ItemFields::ChildCount);
+        }
+
+        let mut request = self.client.get(endpoint_url).query(&query);
+        if query.exclude_specials {
+            request = request.query(&[("IsSpecialSeason", "false")]);
+        }
+
+        let response = self.send_authed(request).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Gets the episodes of a series.
+    ///
+    /// # Arguments
+    ///
+    /// * `series_id` - The ID of the series.
+    /// * `query` - Filters and paging for the episode list.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_episodes<T: Into<String>>(
+        &self,
+        series_id: T,
+        query: &EpisodesQuery,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Shows/{}/Episodes", series_id.into()))
+            .expect("Failed to join URL");
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/tv.rs"]
+mod tests;
@@ -1,3 +1,5 @@
+use std::sync::PoisonError;
+
 use super::err::Result;
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
@@ -6,8 +8,10 @@ use sha1::Digest;
 
 use super::session::SessionInfo;
 use crate::err::JellyfinError;
+use crate::ids::UserId;
+use crate::items::BaseItemDto;
 use crate::serde::subtitle_mode_serde;
-use crate::utils::handle_http_error;
+use crate::utils::{build_emby_auth_header, json_or_none, patch_json, same_id, ClientInfo};
 use crate::JellyfinClient;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -50,6 +54,19 @@ pub struct UserConfiguration {
     pub enable_next_episode_auto_play: bool,
 }
 
+impl UserConfiguration {
+    /// Sorts views, such as those returned by `get_user_views`, in the order the user arranged
+    /// them in. Views missing from `ordered_views` keep their order and come last.
+    pub fn sort_views(&self, views: &mut [BaseItemDto]) {
+        views.sort_by_key(|view| {
+            self.ordered_views
+                .iter()
+                .position(|id| same_id(id, &view.id))
+                .unwrap_or(usize::MAX)
+        });
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub enum SubtitleMode {
@@ -124,10 +141,34 @@ pub struct UserAuth {
 }
 
 impl UserAuth {
-    pub fn to_emby_header(&self) -> String {
-        let device_name = whoami::devicename().replace(' ', "_");
+    /// Builds the `X-Emby-Authorization` header for this session.
+    ///
+    /// Pass the client's `JellyfinClient::client_info`, so the header carries the same device ID
+    /// as the requests the client sends.
+    pub fn to_emby_header(&self, info: &ClientInfo) -> String {
+        build_emby_auth_header(info, Some(&self.access_token))
+    }
+}
+
+/// The credentials a `JellyfinClient` authenticates its requests with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthKind {
+    /// A session obtained by authenticating as a user.
+    User(Box<UserAuth>),
+}
+
+impl AuthKind {
+    /// The access token sent to the server.
+    pub fn token(&self) -> &str {
+        match self {
+            Self::User(auth) => &auth.access_token,
+        }
+    }
 
-        format!("MediaBrowser Client=\"jellyfin-rs\", Device=\"{}\", DeviceId=\"{:x}\", Version=1, Token=\"{}\"",  device_name, md5::compute(device_name.clone()), self.access_token)
+    /// Builds the `X-Emby-Authorization` header for these credentials, identifying the client
+    /// with `info`.
+    pub fn to_emby_header(&self, info: &ClientInfo) -> String {
+        build_emby_auth_header(info, Some(self.token()))
     }
 }
 
@@ -143,6 +184,16 @@ struct AuthUserStdQuery {
     password: String,
 }
 
+/// The body of a request creating a user.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateUserRequest {
+    pub name: String,
+    /// The initial password, or `None` to create the user without a password.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 struct AuthUserNameQuery {
@@ -179,34 +230,16 @@ impl JellyfinClient {
         let endpoint_url = self.url.join("/Users").expect("Failed to join URL");
 
         let response = self
-            .client
-            .get(endpoint_url)
-            .query(&GetUsersQuery {
+            .send_authed(self.client.get(endpoint_url).query(&GetUsersQuery {
                 is_hidden,
                 is_disabled,
-            })
-            .header(
-                "X-Emby-Authorization",
-                self.auth
-                    .as_ref()
-                    .ok_or(JellyfinError::AuthNotFound)?
-                    .to_emby_header(),
-            )
-            .send()
-            .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    resp.json::<Vec<User>>()
-                        .await
-                        .map_err(JellyfinError::NetworkError)
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+            }))
+            .await?;
+
+        response
+            .json::<Vec<User>>()
+            .await
+            .map_err(JellyfinError::NetworkError)
     }
 
     /// Fetches a user by their ID.
@@ -218,38 +251,19 @@ impl JellyfinClient {
     /// # Returns
     ///
     /// A `Result` wrapping the `User` instance if successful, or a `JellyfinError` otherwise.
-    pub async fn get_user_by_id<T: Into<String>>(&self, id: T) -> Result<User> {
+    pub async fn get_user_by_id<T: Into<UserId>>(&self, id: T) -> Result<User> {
         let id_str = id.into();
         let endpoint_url = self
             .url
             .join(&format!("/Users/{}", id_str))
             .expect("Failed to join URL");
 
-        let response = self
-            .client
-            .get(endpoint_url)
-            .header(
-                "X-Emby-Authorization",
-                self.auth
-                    .as_ref()
-                    .ok_or(JellyfinError::AuthNotFound)?
-                    .to_emby_header(),
-            )
-            .send()
-            .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    resp.json::<User>()
-                        .await
-                        .map_err(JellyfinError::NetworkError)
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+
+        response
+            .json::<User>()
+            .await
+            .map_err(JellyfinError::NetworkError)
     }
 
     /// Deletes a user by their ID.
@@ -261,40 +275,23 @@ impl JellyfinClient {
     /// # Returns
     ///
     /// A `Result` indicating success if the user was successfully deleted, or a `JellyfinError` otherwise.
-    pub async fn delete_user<T: Into<String>>(&self, id: T) -> Result<()> {
+    pub async fn delete_user<T: Into<UserId>>(&self, id: T) -> Result<()> {
         let id_str = id.into();
         let endpoint_url = self
             .url
             .join(&format!("/Users/{}", id_str))
             .expect("Failed to join URL");
 
-        let response = self
-            .client
-            .delete(endpoint_url)
-            .header(
-                "X-Emby-Authorization",
-                self.auth
-                    .as_ref()
-                    .ok_or(JellyfinError::AuthNotFound)?
-                    .to_emby_header(),
-            )
-            .send()
-            .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    Ok(())
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+        self.send_authed(self.client.delete(endpoint_url)).await?;
+
+        Ok(())
     }
 
     /// Updates user information for a given user ID.
     ///
+    /// The ID of `new_info` must be empty or name the same user as `id`, so a user is never
+    /// overwritten with the information of another one.
+    ///
     /// # Arguments
     ///
     /// * `id` - The ID of the user to update.
@@ -302,38 +299,24 @@ impl JellyfinClient {
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success if the user information was successfully updated, or a `JellyfinError` otherwise.
+    /// A `Result` indicating success if the user information was successfully updated, `JellyfinError::InvalidInput` if `new_info` holds the ID of another user, or a `JellyfinError` otherwise.
     pub async fn update_user<T: Into<String>>(&self, id: T, new_info: User) -> Result<()> {
         let id_str = id.into();
+        if !new_info.id.is_empty() && !same_id(&new_info.id, &id_str) {
+            return Err(JellyfinError::InvalidInput(format!(
+                "User ID {} does not match the ID {} of the user information",
+                id_str, new_info.id
+            )));
+        }
         let endpoint_url = self
             .url
             .join(&format!("/Users/{}", id_str))
             .expect("Failed to join URL");
 
-        let response = self
-            .client
-            .post(endpoint_url)
-            .json(&new_info)
-            .header(
-                "X-Emby-Authorization",
-                self.auth
-                    .as_ref()
-                    .ok_or(JellyfinError::AuthNotFound)?
-                    .to_emby_header(),
-            )
-            .send()
-            .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    Ok(())
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+        self.send_authed(self.client.post(endpoint_url).json(&new_info))
+            .await?;
+
+        Ok(())
     }
 
     /// Authenticates a user by standard method using user ID and password.
@@ -346,42 +329,25 @@ impl JellyfinClient {
     /// # Returns
     ///
     /// A `Result` indicating success if the user was authenticated successfully, or a `JellyfinError` otherwise.
-    pub async fn auth_user_std<T: Into<String> + Clone>(
-        &mut self,
-        id: T,
-        password: T,
-    ) -> Result<()> {
+    pub async fn auth_user_std<T: Into<String> + Clone>(&self, id: T, password: T) -> Result<()> {
         let mut hasher = sha1::Sha1::new();
         hasher.update(password.clone().into());
-        let device_name = whoami::devicename().replace(' ', "_");
-
         let endpoint_url = self
             .url
             .join(&format!("/Users/{}/Authenticate", id.clone().into()))
             .expect("Failed to join URL");
 
         let response = self
-        .client
-        .post(endpoint_url)
-        .query(&AuthUserStdQuery {
-            pw: password.into(),
-            password: format!("{:x}", hasher.finalize()),
-        })
-        .header("X-Emby-Authorization", format!("MediaBrowser Client=\"jellyfin-rs\", Device=\"{}\", DeviceId=\"{:x}\", Version=1, Token=\"\"", device_name, md5::compute(device_name.clone())))
-        .send()
-        .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    self.auth = Some(resp.json().await.map_err(JellyfinError::NetworkError)?);
-                    Ok(())
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+            .send_anonymous(self.client.post(endpoint_url).query(&AuthUserStdQuery {
+                pw: password.into(),
+                password: format!("{:x}", hasher.finalize()),
+            }))
+            .await?;
+
+        self.set_auth(AuthKind::User(Box::new(
+            response.json().await.map_err(JellyfinError::NetworkError)?,
+        )));
+        Ok(())
     }
 
     /// Updates the configuration for a given user ID.
@@ -404,30 +370,77 @@ impl JellyfinClient {
             .join(&format!("/Users/{}/Configuration", id.into()))
             .expect("Failed to join URL");
 
-        let response = self
-            .client
-            .post(endpoint_url)
-            .json(&new_conf)
-            .header(
-                "X-Emby-Authorization",
-                self.auth
-                    .as_ref()
-                    .ok_or(JellyfinError::AuthNotFound)?
-                    .to_emby_header(),
-            )
-            .send()
-            .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    Ok(())
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+        self.send_authed(self.client.post(endpoint_url).json(&new_conf))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Updates part of the configuration for a given user ID.
+    ///
+    /// Fetches the user's current configuration, applies `patch` to it and posts the result back,
+    /// so only the fields changed by `patch` are modified. Fields that `UserConfiguration` does not
+    /// model are sent back exactly as the server returned them.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the user whose configuration is to be updated.
+    /// * `patch` - A closure modifying the current configuration.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the updated `UserConfiguration` if successful, or a `JellyfinError` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    ///  use jellyfin_rs::JellyfinClient;
+    ///
+    /// async fn disable_auto_play(client: &JellyfinClient, user_id: &str) {
+    ///     let result = client
+    ///         .patch_user_conf(user_id, |conf| conf.enable_next_episode_auto_play = false)
+    ///         .await;
+    ///     if let Err(e) = result {
+    ///         eprintln!("Error updating configuration: {:?}", e);
+    ///     }
+    /// }
+    /// ```
+    pub async fn patch_user_conf<T: Into<String>, F: FnOnce(&mut UserConfiguration)>(
+        &self,
+        id: T,
+        patch: F,
+    ) -> Result<UserConfiguration> {
+        let id = id.into();
+        let mut raw = self.get_user_section_json(&id, "Configuration").await?;
+        let conf = patch_json(&mut raw, patch)?;
+
+        let endpoint_url = self
+            .url
+            .join(&format!("/Users/{}/Configuration", id))
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.post(endpoint_url).json(&raw))
+            .await?;
+
+        Ok(conf)
+    }
+
+    /// Fetches a section of a user, such as `"Configuration"` or `"Policy"`, as raw JSON, keeping
+    /// the fields this crate does not model.
+    async fn get_user_section_json(&self, id: &str, section: &str) -> Result<serde_json::Value> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Users/{}", id))
+            .expect("Failed to join URL");
+
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+        let mut user: serde_json::Value =
+            response.json().await.map_err(JellyfinError::NetworkError)?;
+
+        Ok(user
+            .get_mut(section)
+            .map(serde_json::Value::take)
+            .unwrap_or_else(|| json!({})))
     }
 
     /// Updates the password for a given user ID.
@@ -450,30 +463,14 @@ impl JellyfinClient {
             .join(&format!("/Users/{}/Password", id.into()))
             .expect("Failed to join URL");
 
-        let response = self
-            .client
-            .post(endpoint_url)
-            .json(&json!({ "NewPw": new_password.into() }))
-            .header(
-                "X-Emby-Authorization",
-                self.auth
-                    .as_ref()
-                    .ok_or(JellyfinError::AuthNotFound)?
-                    .to_emby_header(),
-            )
-            .send()
-            .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    Ok(())
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+        self.send_authed(
+            self.client
+                .post(endpoint_url)
+                .json(&json!({ "NewPw": new_password.into() })),
+        )
+        .await?;
+
+        Ok(())
     }
 
     /// Updates the policy for a given user ID.
@@ -496,30 +493,95 @@ impl JellyfinClient {
             .join(&format!("/Users/{}/Policy", id.into()))
             .expect("Failed to join URL");
 
-        let response = self
-            .client
-            .post(endpoint_url)
-            .json(&new_policy)
-            .header(
-                "X-Emby-Authorization",
-                self.auth
-                    .as_ref()
-                    .ok_or(JellyfinError::AuthNotFound)?
-                    .to_emby_header(),
-            )
-            .send()
-            .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    Ok(())
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+        self.send_authed(self.client.post(endpoint_url).json(&new_policy))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Updates part of the policy for a given user ID.
+    ///
+    /// Fetches the user's current policy, applies `patch` to it and posts the result back, so only
+    /// the fields changed by `patch` are modified. Fields that `UserPolicy` does not model, such as
+    /// permissions added by newer servers, are sent back exactly as the server returned them.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the user whose policy is to be updated.
+    /// * `patch` - A closure modifying the current policy.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the updated `UserPolicy` if successful, or a `JellyfinError` otherwise.
+    pub async fn patch_user_policy<T: Into<String>, F: FnOnce(&mut UserPolicy)>(
+        &self,
+        id: T,
+        patch: F,
+    ) -> Result<UserPolicy> {
+        let id = id.into();
+        let mut raw = self.get_user_section_json(&id, "Policy").await?;
+        let policy = patch_json(&mut raw, patch)?;
+
+        let endpoint_url = self
+            .url
+            .join(&format!("/Users/{}/Policy", id))
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.post(endpoint_url).json(&raw))
+            .await?;
+
+        Ok(policy)
+    }
+
+    /// Allows or denies a user access from outside the local network.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the user.
+    /// * `enabled` - Whether remote access is allowed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success if the user's policy was successfully updated, or a `JellyfinError` otherwise.
+    pub async fn set_remote_access<T: Into<String>>(&self, id: T, enabled: bool) -> Result<()> {
+        self.patch_user_policy(id, |policy| policy.enable_remote_access = enabled)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Allows or denies a user media playback.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the user.
+    /// * `enabled` - Whether media playback is allowed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success if the user's policy was successfully updated, or a `JellyfinError` otherwise.
+    pub async fn set_media_playback<T: Into<String>>(&self, id: T, enabled: bool) -> Result<()> {
+        self.patch_user_policy(id, |policy| policy.enable_media_playback = enabled)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Allows or denies a user downloading media.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the user.
+    /// * `enabled` - Whether content downloading is allowed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success if the user's policy was successfully updated, or a `JellyfinError` otherwise.
+    pub async fn set_downloading<T: Into<String>>(&self, id: T, enabled: bool) -> Result<()> {
+        self.patch_user_policy(id, |policy| policy.enable_content_downloading = enabled)
+            .await?;
+
+        Ok(())
     }
 
     /// Authenticates a user by their username and password.
@@ -545,7 +607,7 @@ impl JellyfinClient {
     /// ```no_run
     ///  use jellyfin_rs::JellyfinClient;
     ///
-    /// async fn authenticate_user(client: &mut JellyfinClient) {
+    /// async fn authenticate_user(client: &JellyfinClient) {
     ///     let username = "exampleUser";
     ///     let password = "examplePassword";
     ///     match client.auth_user_name(username, password).await {
@@ -560,39 +622,24 @@ impl JellyfinClient {
     /// Upon successful authentication, the user's authentication token is stored in the client instance,
     /// allowing subsequent requests to be made as the authenticated user. This token should be protected,
     /// and the client instance should not be shared with untrusted code.
-    pub async fn auth_user_name<T: Into<String>>(
-        &mut self,
-        username: T,
-        password: T,
-    ) -> Result<()> {
-        let device_name = whoami::devicename().replace(' ', "_");
-
+    pub async fn auth_user_name<T: Into<String>>(&self, username: T, password: T) -> Result<()> {
         let endpoint_url = self
             .url
             .join("/Users/AuthenticateByName")
             .expect("Failed to join URL");
 
-        let response = self.client.post(endpoint_url)
-            .json(&AuthUserNameQuery {
+        let response = self
+            .send_anonymous(self.client.post(endpoint_url).json(&AuthUserNameQuery {
                 username: username.into(),
                 pw: password.into(),
-            })
-            .header("X-Emby-Authorization", format!("MediaBrowser Client=\"jellyfin-rs\", Device=\"{}\", DeviceId=\"{:x}\", Version=1, Token=\"\"", device_name, md5::compute(device_name.clone())))
-            .send()
-            .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    self.auth = Some(resp.json().await.map_err(JellyfinError::NetworkError)?);
-
-                    Ok(())
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+            }))
+            .await?;
+
+        self.set_auth(AuthKind::User(Box::new(
+            response.json().await.map_err(JellyfinError::NetworkError)?,
+        )));
+
+        Ok(())
     }
 
     /// Initiates the forgot password process for a given username.
@@ -605,29 +652,17 @@ impl JellyfinClient {
     ///
     /// A `Result` indicating success if the process was initiated successfully, or a `JellyfinError` otherwise.
     pub async fn user_forgot_password<T: Into<String>>(&self, username: T) -> Result<()> {
-        let device_name = whoami::devicename().replace(' ', "_");
         let endpoint_url = self
             .url
             .join("/Users/ForgotPassword")
             .expect("Failed to join URL");
 
-        let response = self.client.post(endpoint_url).json(&json!({
+        self.send_anonymous(self.client.post(endpoint_url).json(&json!({
             "EnteredUsername": username.into()
-        }))
-        .header("X-Emby-Authorization", format!("MediaBrowser Client=\"jellyfin-rs\", Device=\"{}\", DeviceId=\"{:x}\", Version=1, Token=\"\"", device_name, md5::compute(device_name.clone())))
-        .send()
-        .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    Ok(())
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+        })))
+        .await?;
+
+        Ok(())
     }
 
     /// Redeems a forgot password PIN for resetting the password.
@@ -640,32 +675,17 @@ impl JellyfinClient {
     ///
     /// A `Result` indicating success if the PIN was redeemed successfully, or a `JellyfinError` otherwise.
     pub async fn user_redeem_forgot_password_pin<T: Into<String>>(&self, pin: T) -> Result<()> {
-        let device_name = whoami::devicename().replace(' ', "_");
         let endpoint_url = self
             .url
             .join("/Users/ForgotPassword/Pin")
             .expect("Failed to join URL");
 
-        let response = self
-        .client
-        .post(endpoint_url)
-        .json(&json!({
+        self.send_anonymous(self.client.post(endpoint_url).json(&json!({
             "Pin": pin.into()
-        }))
-        .header("X-Emby-Authorization", format!("MediaBrowser Client=\"jellyfin-rs\", Device=\"{}\", DeviceId=\"{:x}\", Version=1, Token=\"\"", device_name, md5::compute(device_name.clone())))
-        .send()
-        .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    Ok(())
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+        })))
+        .await?;
+
+        Ok(())
     }
 
     /// Retrieves the user authenticated by the current session.
@@ -676,29 +696,49 @@ impl JellyfinClient {
     pub async fn get_user_by_auth(&self) -> Result<User> {
         let endpoint_url = self.url.join("/Users/Me").expect("Failed to join URL");
 
-        let response = self
-            .client
-            .get(endpoint_url)
-            .header(
-                "X-Emby-Authorization",
-                self.auth
-                    .as_ref()
-                    .ok_or(JellyfinError::AuthNotFound)?
-                    .to_emby_header(),
-            )
-            .send()
-            .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    resp.json().await.map_err(JellyfinError::NetworkError)
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
+        let response = self.send_authed(self.client.get(endpoint_url)).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Returns the authenticated user, as received when logging in, without a network round trip.
+    ///
+    /// The user is shared by all clones of the client, so a clone of it is returned. It is `None`
+    /// when the client is not authenticated. Use `refresh_current_user` to reload it after it
+    /// changed on the server.
+    pub fn current_user(&self) -> Option<User> {
+        match self
+            .auth
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()?
+        {
+            AuthKind::User(auth) => Some(auth.user.clone()),
+        }
+    }
+
+    /// Reloads the authenticated user from the server and updates the one returned by
+    /// `current_user`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the reloaded `User` instance if successful, or a `JellyfinError` otherwise.
+    pub async fn refresh_current_user(&self) -> Result<User> {
+        let user = self.get_user_by_auth().await?;
+
+        if let Some(AuthKind::User(auth)) = self
+            .auth
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            // Only update the session the user was fetched for, in case it was replaced meanwhile.
+            if auth.user.id == user.id {
+                auth.user = user.clone();
             }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
         }
+
+        Ok(user)
     }
 
     /// Creates a new user with the specified username and password.
@@ -712,35 +752,41 @@ impl JellyfinClient {
     ///
     /// A `Result` wrapping the newly created `User` instance if successful, or a `JellyfinError` otherwise.
     pub async fn create_user<T: Into<String>>(&self, username: T, password: T) -> Result<User> {
+        self.create_user_with(&CreateUserRequest {
+            name: username.into(),
+            password: Some(password.into()),
+        })
+        .await
+    }
+
+    /// Creates a new user from a `CreateUserRequest`.
+    ///
+    /// Older servers answer with an empty body, in which case the new user is looked up by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The name and initial settings of the new user.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the newly created `User` instance if successful, or a `JellyfinError` otherwise.
+    pub async fn create_user_with(&self, request: &CreateUserRequest) -> Result<User> {
         let endpoint_url = self.url.join("/Users/New").expect("Failed to join URL");
 
         let response = self
-            .client
-            .post(endpoint_url)
-            .json(&json!({
-                "Name": username.into(),
-                "Password": password.into()
-            }))
-            .header(
-                "X-Emby-Authorization",
-                self.auth
-                    .as_ref()
-                    .ok_or(JellyfinError::AuthNotFound)?
-                    .to_emby_header(),
-            )
-            .send()
-            .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    resp.json().await.map_err(JellyfinError::NetworkError)
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
+            .send_authed(self.client.post(endpoint_url).json(request))
+            .await?;
+
+        if let Some(user) = json_or_none(response).await? {
+            return Ok(user);
         }
+
+        // Some older servers create the user but answer with an empty body, so look it up instead.
+        self.get_users(false, false)
+            .await?
+            .into_iter()
+            .find(|user| user.name == request.name)
+            .ok_or_else(|| JellyfinError::not_found(format!("Created user {}", request.name)))
     }
 
     /// Retrieves a list of public users.
@@ -749,24 +795,11 @@ impl JellyfinClient {
     ///
     /// A `Result` wrapping a vector of `User` instances if successful, or a `JellyfinError` otherwise.
     pub async fn get_public_user_list(&self) -> Result<Vec<User>> {
-        let device_name = whoami::devicename().replace(' ', "_");
         let endpoint_url = self.url.join("/Users/Public").expect("Failed to join URL");
 
-        let response = self.client.get(endpoint_url)
-        .header("X-Emby-Authorization", format!("MediaBrowser Client=\"jellyfin-rs\", Device=\"{}\", DeviceId=\"{:x}\", Version=1, Token=\"\"", device_name, md5::compute(device_name.clone())))
-        .send()
-        .await;
-
-        match response {
-            Ok(resp) => {
-                if resp.status().is_success() {
-                    resp.json().await.map_err(JellyfinError::NetworkError)
-                } else {
-                    Err(handle_http_error(resp).await)
-                }
-            }
-            Err(e) => Err(JellyfinError::NetworkError(e)),
-        }
+        let response = self.send_anonymous(self.client.get(endpoint_url)).await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
     }
 }
 
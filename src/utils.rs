@@ -1,10 +1,213 @@
+use std::fs;
+use std::path::Path;
+
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use crate::err::JellyfinError;
+use crate::err::Result;
+
+/// The query of endpoints that only take the ID of the requesting user.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct UserIdQuery {
+    pub user_id: String,
+}
+
+/// How this client identifies itself to the server in the `X-Emby-Authorization` header.
+///
+/// The server ties sessions, and the devices listed in the dashboard, to the device ID. Set it
+/// with `JellyfinClient::with_client_info`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientInfo {
+    pub client: String,
+    pub device: String,
+    pub device_id: String,
+    pub version: String,
+}
+
+impl ClientInfo {
+    /// The name of the file `persistent` keeps the device ID in.
+    const DEVICE_ID_FILE: &'static str = "device_id";
+
+    /// The client information of this machine, with the device ID derived from its device name.
+    pub fn current() -> Self {
+        let device = sanitize_header_value(&whoami::devicename());
+
+        Self {
+            client: "jellyfin-rs".to_string(),
+            device_id: format!("{:x}", md5::compute(&device)),
+            device,
+            version: "1".to_string(),
+        }
+    }
+
+    /// The client information of this machine, with a random device ID kept in a file under
+    /// `app_dir`, so the same installation keeps its device ID across restarts.
+    ///
+    /// The device ID is generated and written on first use. If the file cannot be read, a new
+    /// device ID is generated and written; if it cannot be written either, the device ID only
+    /// lasts as long as the process.
+    pub fn persistent<P: AsRef<Path>>(app_dir: P) -> Self {
+        let path = app_dir.as_ref().join(Self::DEVICE_ID_FILE);
+
+        let device_id = match fs::read_to_string(&path) {
+            Ok(device_id) if !device_id.trim().is_empty() => device_id.trim().to_string(),
+            _ => {
+                let device_id = random_uuid();
+                // Best effort: without the file, the device ID is simply not kept.
+                let _ = fs::create_dir_all(app_dir.as_ref())
+                    .and_then(|()| fs::write(&path, &device_id));
+                device_id
+            }
+        };
+
+        Self {
+            device_id,
+            ..Self::current()
+        }
+    }
+}
+
+/// Generates a random (version 4) UUID.
+fn random_uuid() -> String {
+    let mut bytes: [u8; 16] = rand::random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// Makes a value safe to embed in a quoted `X-Emby-Authorization` field.
+///
+/// Quotes, backslashes and commas would end the field early or split it, and spaces and control
+/// characters are not reliably preserved by the server's parser, so all of them become `_`.
+fn sanitize_header_value(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '"' | '\\' | ',' => '_',
+            c if c.is_whitespace() || c.is_control() => '_',
+            c => c,
+        })
+        .collect()
+}
+
+/// Builds the `X-Emby-Authorization` header value identifying the client, with the token of the
+/// current session, or an empty token for anonymous requests.
+pub(crate) fn build_emby_auth_header(info: &ClientInfo, token: Option<&str>) -> String {
+    format!(
+        "MediaBrowser Client=\"{}\", Device=\"{}\", DeviceId=\"{}\", Version={}, Token=\"{}\"",
+        sanitize_header_value(&info.client),
+        sanitize_header_value(&info.device),
+        sanitize_header_value(&info.device_id),
+        sanitize_header_value(&info.version),
+        sanitize_header_value(token.unwrap_or_default()),
+    )
+}
+
+/// Compares two IDs, ignoring the dashes and case the server may format them with.
+pub(crate) fn same_id(a: &str, b: &str) -> bool {
+    let normalize = |id: &str| {
+        id.chars()
+            .filter(|c| *c != '-')
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<String>()
+    };
+
+    normalize(a) == normalize(b)
+}
+
+/// Decodes a JSON response body, or returns `None` if the server answered with `204 No Content`
+/// or an empty body, as some endpoints do on older servers.
+pub(crate) async fn json_or_none<T: DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<Option<T>> {
+    if response.status() == StatusCode::NO_CONTENT {
+        return Ok(None);
+    }
+
+    let body = response.bytes().await?;
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(None);
+    }
+
+    Ok(Some(serde_json::from_slice(&body)?))
+}
+
+/// Trims a value such as a tag or a library name, rejecting empty values before any request is
+/// made.
+pub(crate) fn non_empty_label(kind: &str, value: String) -> Result<String> {
+    let value = value.trim();
+
+    if value.is_empty() {
+        return Err(JellyfinError::InvalidInput(format!(
+            "{} must not be empty",
+            kind
+        )));
+    }
+
+    Ok(value.to_string())
+}
+
+/// Applies `patch` to the typed form of `raw`, then copies back into `raw` only the keys `patch`
+/// changed, so fields this crate does not model are sent back exactly as the server returned them.
+pub(crate) fn patch_json<T, F>(raw: &mut Value, patch: F) -> Result<T>
+where
+    T: serde::Serialize + DeserializeOwned,
+    F: FnOnce(&mut T),
+{
+    let mut typed: T = serde_json::from_value(raw.clone())?;
+    let before = serde_json::to_value(&typed)?;
+    patch(&mut typed);
+    let after = serde_json::to_value(&typed)?;
+
+    if let (Some(raw), Some(before), Some(after)) =
+        (raw.as_object_mut(), before.as_object(), after.as_object())
+    {
+        for (key, value) in after {
+            if before.get(key) != Some(value) {
+                raw.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    Ok(typed)
+}
+
+/// Turns a `403 Forbidden` response into `JellyfinError::Forbidden` with the given message.
+pub(crate) fn forbidden_as(err: JellyfinError, message: &str) -> JellyfinError {
+    match err {
+        JellyfinError::HttpRequestError { status, .. }
+            if status == StatusCode::FORBIDDEN.as_u16() =>
+        {
+            JellyfinError::Forbidden(message.to_string())
+        }
+        err => err,
+    }
+}
 
 pub async fn handle_http_error(resp: reqwest::Response) -> JellyfinError {
     let status_code = resp.status().as_u16();
     let body = resp.text().await.unwrap_or_default();
 
     if let Ok(parsed_body) = serde_json::from_str::<serde_json::Value>(&body) {
+        // Some endpoints answer with a bare JSON string, such as `"User not found"`.
+        let message = match &parsed_body {
+            serde_json::Value::String(message) => message.clone(),
+            _ => body.clone(),
+        };
+
         JellyfinError::HttpRequestError {
             status: status_code,
             type_: parsed_body
@@ -31,19 +234,19 @@ pub async fn handle_http_error(resp: reqwest::Response) -> JellyfinError {
                 .get("property2")
                 .and_then(|v| v.as_str())
                 .map(String::from),
-            message: body,
+            message,
+            body: Some(body),
         }
     } else {
         // Fallback to a simple error message if the response is not JSON or cannot be parsed
-        JellyfinError::HttpRequestError {
-            status: status_code,
-            message: body,
-            type_: None,
-            title: None,
-            detail: None,
-            instance: None,
-            property1: None,
-            property2: None,
+        let mut err = JellyfinError::http(status_code, body.clone());
+        if let JellyfinError::HttpRequestError { body: raw, .. } = &mut err {
+            *raw = Some(body);
         }
+        err
     }
 }
+
+#[cfg(test)]
+#[path = "tests/utils.rs"]
+mod tests;
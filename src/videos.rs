@@ -0,0 +1,232 @@
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use url::Url;
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::items::{BaseItemDto, QueryResult};
+use crate::serde::comma_separated;
+use crate::utils::{forbidden_as, UserIdQuery};
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct MergeVersionsQuery {
+    #[serde(with = "comma_separated")]
+    ids: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VideoStreamQuery {
+    /// Streams the original file without transcoding or remuxing it.
+    #[serde(rename = "Static", skip_serializing_if = "Option::is_none")]
+    pub static_: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_source_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_session_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_codec: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_codec: Option<String>,
+    /// The maximum bitrate of the stream, in bits per second.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_streaming_bitrate: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_stream_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle_stream_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_ticks: Option<i64>,
+}
+
+impl JellyfinClient {
+    /// Gets the additional parts of a video that is split across several files, such as `CD1` and
+    /// `CD2`.
+    ///
+    /// The parts are returned in playback order and each one carries its own media sources, so they
+    /// can be queued after the video itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the first part of the video.
+    /// * `user_id` - The ID of the user.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a `QueryResult` of `BaseItemDto` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn get_additional_parts<T: Into<String>>(
+        &self,
+        item_id: T,
+        user_id: T,
+    ) -> Result<QueryResult<BaseItemDto>> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Videos/{}/AdditionalParts", item_id.into()))
+            .expect("Failed to join URL");
+
+        let query = UserIdQuery {
+            user_id: user_id.into(),
+        };
+
+        let response = self
+            .send_authed(self.client.get(endpoint_url).query(&query))
+            .await?;
+
+        response.json().await.map_err(JellyfinError::NetworkError)
+    }
+
+    /// Builds the URL a media player can stream a video from.
+    ///
+    /// The URL carries the current access token, so the player does not need to send any headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the video.
+    /// * `query` - The stream parameters, such as the media source and codecs. Without a
+    ///   `media_source_id`, the server streams the default version of items with several
+    ///   versions; use `media_source_stream_url` to target a version safely.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the stream `Url` if successful, or `JellyfinError::AuthNotFound` if the client is not authenticated.
+    pub fn video_stream_url<T: Into<String>>(
+        &self,
+        item_id: T,
+        query: &VideoStreamQuery,
+    ) -> Result<Url> {
+        self.authed_url(&format!("/Videos/{}/stream", item_id.into()), query)
+    }
+
+    /// Builds the URL a media player can stream one version (media source) of a video from.
+    ///
+    /// The media source is checked against the playback info of the item first, so a URL is never
+    /// built for a version the item does not have.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the video.
+    /// * `media_source_id` - The ID of the media source to stream, from `get_playback_info`.
+    /// * `query` - The other stream parameters. Its `media_source_id` is replaced.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the stream `Url` if successful, `JellyfinError::NotFound` if the item has no such media source, or another `JellyfinError` otherwise.
+    pub async fn media_source_stream_url<T: Into<String>>(
+        &self,
+        item_id: T,
+        media_source_id: T,
+        query: &VideoStreamQuery,
+    ) -> Result<Url> {
+        let (item_id, media_source_id) = (item_id.into(), media_source_id.into());
+        let info = self.get_playback_info(item_id.as_str()).await?;
+        info.media_source(&item_id, &media_source_id)?;
+
+        let query = VideoStreamQuery {
+            media_source_id: Some(media_source_id),
+            play_session_id: query.play_session_id.clone().or(info.play_session_id),
+            ..query.clone()
+        };
+
+        self.video_stream_url(item_id, &query)
+    }
+
+    /// Builds the URL a media player can stream a video from, limiting the bitrate to the
+    /// authenticated user's remote client bitrate limit.
+    ///
+    /// The server rejects streams above the limit set in the user's policy, so this clamps
+    /// `max_streaming_bitrate` to that limit, the same way the web client does. A limit of `0`
+    /// means unlimited and leaves the query unchanged. The limit is read from `current_user`, so
+    /// no request is made, and it is applied whether or not the client is on the server's local
+    /// network, where the server itself would not enforce it.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the video.
+    /// * `query` - The stream parameters, such as the media source and codecs.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the stream `Url` if successful, `JellyfinError::AuthNotFound` if the client is not authenticated, or another `JellyfinError` otherwise.
+    pub async fn video_stream_url_respecting_policy<T: Into<String>>(
+        &self,
+        item_id: T,
+        query: &VideoStreamQuery,
+    ) -> Result<Url> {
+        let limit = self
+            .current_user()
+            .ok_or(JellyfinError::AuthNotFound)?
+            .policy
+            .remote_client_bitrate_limit;
+
+        let mut query = query.clone();
+        if limit > 0 {
+            query.max_streaming_bitrate = Some(
+                query
+                    .max_streaming_bitrate
+                    .map_or(limit, |bitrate| bitrate.min(limit)),
+            );
+        }
+
+        self.video_stream_url(item_id, &query)
+    }
+
+    /// Merges videos into a single item with alternate versions, such as a 1080p and a 4K file of
+    /// the same movie. Requires an administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_ids` - The IDs of the videos to merge.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, `JellyfinError::Forbidden` if the user is not an administrator, or another `JellyfinError` otherwise.
+    pub async fn merge_versions<T: AsRef<str>>(&self, item_ids: &[T]) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join("/Videos/MergeVersions")
+            .expect("Failed to join URL");
+
+        let query = MergeVersionsQuery {
+            ids: item_ids.iter().map(|id| id.as_ref().to_string()).collect(),
+        };
+
+        self.send_authed(self.client.post(endpoint_url).query(&query))
+            .await
+            .map_err(|err| forbidden_as(err, "Merging versions requires an administrator"))?;
+
+        Ok(())
+    }
+
+    /// Splits a merged video back into separate items, one per version. Requires an
+    /// administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the merged video.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, `JellyfinError::Forbidden` if the user is not an administrator, or another `JellyfinError` otherwise.
+    pub async fn split_versions<T: Into<String>>(&self, item_id: T) -> Result<()> {
+        let endpoint_url = self
+            .url
+            .join(&format!("/Videos/{}/AlternateSources", item_id.into()))
+            .expect("Failed to join URL");
+
+        self.send_authed(self.client.delete(endpoint_url))
+            .await
+            .map_err(|err| forbidden_as(err, "Splitting versions requires an administrator"))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/videos.rs"]
+mod tests;
@@ -0,0 +1,526 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_util::{future, stream, SinkExt, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use serde_json::Value;
+use tokio::net::TcpStream;
+use tokio::time::{Instant, Interval};
+use tokio_tungstenite::tungstenite::{self, http::StatusCode, Message};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::err::JellyfinError;
+use crate::err::Result;
+use crate::session::SessionInfo;
+use crate::JellyfinClient;
+
+/// How often, in milliseconds, `playback_events` asks the server for session updates.
+const SESSIONS_INTERVAL_MS: u64 = 1000;
+
+/// A message exchanged over the server's WebSocket.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WebSocketMessage {
+    /// The kind of message, e.g. `"Sessions"`, `"UserDataChanged"` or `"ForceKeepAlive"`.
+    pub message_type: String,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub data: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+}
+
+impl WebSocketMessage {
+    /// Decodes the payload of the message.
+    pub fn data_as<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_value(self.data.clone())?)
+    }
+}
+
+/// A WebSocket connection to the server.
+///
+/// The connection is a stream of the messages sent by the server. Frames that are not JSON
+/// messages, such as pings, are skipped.
+///
+/// The server closes connections that stay silent for too long, and tells how long with a
+/// `ForceKeepAlive` message. While the stream is polled, `KeepAlive` messages are sent in reply
+/// and then every half of that timeout, like the web client does.
+pub struct JellyfinWebSocket {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    keep_alive: Option<Interval>,
+    keep_alive_interval: Option<Duration>,
+    /// Whether a `KeepAlive` message is due but could not be queued yet.
+    keep_alive_due: bool,
+    /// Whether a queued `KeepAlive` message still has to be flushed.
+    flush_pending: bool,
+}
+
+impl JellyfinWebSocket {
+    fn new(stream: WebSocketStream<MaybeTlsStream<TcpStream>>) -> Self {
+        Self {
+            stream,
+            keep_alive: None,
+            keep_alive_interval: None,
+            keep_alive_due: false,
+            flush_pending: false,
+        }
+    }
+
+    /// The interval `KeepAlive` messages are sent at, or `None` until the server has sent
+    /// `ForceKeepAlive`.
+    pub fn keep_alive_interval(&self) -> Option<Duration> {
+        self.keep_alive_interval
+    }
+
+    /// Schedules `KeepAlive` messages for the timeout, in seconds, of a `ForceKeepAlive` message.
+    fn start_keep_alive(&mut self, message: &WebSocketMessage) {
+        let Some(timeout) = message.data.as_f64().filter(|timeout| *timeout > 0.0) else {
+            return;
+        };
+
+        let interval = Duration::from_secs_f64(timeout / 2.0);
+        self.keep_alive_interval = Some(interval);
+        self.keep_alive = Some(tokio::time::interval_at(
+            Instant::now() + interval,
+            interval,
+        ));
+        self.keep_alive_due = true;
+    }
+
+    /// Sends the `KeepAlive` messages that are due, without blocking the messages being read.
+    fn poll_keep_alive(&mut self, cx: &mut Context<'_>) -> Result<()> {
+        if let Some(keep_alive) = self.keep_alive.as_mut() {
+            while keep_alive.poll_tick(cx).is_ready() {
+                self.keep_alive_due = true;
+            }
+        }
+
+        if self.keep_alive_due {
+            if let Poll::Ready(ready) = self.stream.poll_ready_unpin(cx) {
+                ready?;
+                let message = WebSocketMessage {
+                    message_type: "KeepAlive".to_string(),
+                    ..Default::default()
+                };
+                self.stream
+                    .start_send_unpin(Message::Text(serde_json::to_string(&message)?))?;
+                self.keep_alive_due = false;
+                self.flush_pending = true;
+            }
+        }
+
+        if self.flush_pending {
+            if let Poll::Ready(flushed) = self.stream.poll_flush_unpin(cx) {
+                flushed?;
+                self.flush_pending = false;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sends a message to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_type` - The kind of message, e.g. `"SessionsStart"`.
+    /// * `data` - The payload of the message.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping `()` if successful, or a `JellyfinError` otherwise.
+    pub async fn send<T: Into<String>>(&mut self, message_type: T, data: Value) -> Result<()> {
+        let message = WebSocketMessage {
+            message_type: message_type.into(),
+            data,
+            message_id: None,
+        };
+
+        self.stream
+            .send(Message::Text(serde_json::to_string(&message)?))
+            .await
+            .map_err(JellyfinError::from)
+    }
+
+    /// Closes the connection.
+    pub async fn close(mut self) -> Result<()> {
+        self.stream.close(None).await.map_err(JellyfinError::from)
+    }
+}
+
+impl Stream for JellyfinWebSocket {
+    type Item = Result<WebSocketMessage>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Err(err) = self.poll_keep_alive(cx) {
+                return Poll::Ready(Some(Err(err)));
+            }
+
+            match self.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(Message::Text(text)))) => {
+                    let message = serde_json::from_str::<WebSocketMessage>(&text);
+                    if let Ok(message) = &message {
+                        if message.message_type == "ForceKeepAlive" {
+                            self.start_keep_alive(message);
+                            // Send the first `KeepAlive` right away.
+                            if let Err(err) = self.poll_keep_alive(cx) {
+                                return Poll::Ready(Some(Err(err)));
+                            }
+                        }
+                    }
+                    return Poll::Ready(Some(message.map_err(Into::into)));
+                }
+                Poll::Ready(Some(Ok(_))) => continue,
+                Poll::Ready(Some(Err(tungstenite::Error::ConnectionClosed))) => {
+                    return Poll::Ready(None)
+                }
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err.into()))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// How `connect_websocket_reconnecting` retries after the connection is lost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// The delay before the first reconnection attempt.
+    pub initial_delay: Duration,
+    /// The longest delay between two attempts; the delay doubles after every failed attempt up
+    /// to this value.
+    pub max_delay: Duration,
+    /// How many attempts in a row may fail before giving up, or `None` to retry forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
+
+/// An event of a WebSocket connection that reconnects on its own.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebSocketEvent {
+    /// A message sent by the server.
+    Message(WebSocketMessage),
+    /// The connection was lost, and the given reconnection attempt starts after `delay`.
+    Reconnecting { attempt: u32, delay: Duration },
+    /// The connection was established again, and the subscriptions were sent again.
+    Reconnected,
+}
+
+struct ReconnectState {
+    client: JellyfinClient,
+    subscriptions: Vec<WebSocketMessage>,
+    policy: ReconnectPolicy,
+    socket: Option<JellyfinWebSocket>,
+    attempt: u32,
+    /// Whether the `Reconnecting` event of the current attempt was already emitted.
+    announced: bool,
+    done: bool,
+}
+
+impl ReconnectState {
+    async fn connect(&mut self) -> Result<JellyfinWebSocket> {
+        let token = self.client.access_token().ok();
+        let mut socket = match self.client.connect_websocket().await {
+            Err(JellyfinError::WebSocketError(err))
+                if matches!(&*err, tungstenite::Error::Http(response)
+                    if response.status() == StatusCode::UNAUTHORIZED) =>
+            {
+                // The server may have restarted and forgotten the token.
+                if !self.client.reauthenticate(token.as_deref()).await {
+                    return Err(JellyfinError::WebSocketError(err));
+                }
+                self.client.connect_websocket().await?
+            }
+            result => result?,
+        };
+
+        for subscription in &self.subscriptions {
+            socket
+                .send(subscription.message_type.clone(), subscription.data.clone())
+                .await?;
+        }
+
+        Ok(socket)
+    }
+
+    async fn next_event(&mut self) -> Option<Result<WebSocketEvent>> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            if let Some(socket) = self.socket.as_mut() {
+                match socket.next().await {
+                    Some(Ok(message)) => return Some(Ok(WebSocketEvent::Message(message))),
+                    // A single frame that could not be decoded; the connection is still fine.
+                    Some(Err(err @ JellyfinError::JsonError(_))) => return Some(Err(err)),
+                    // The connection was lost; reconnect below.
+                    Some(Err(_)) | None => {
+                        self.socket = None;
+                        self.attempt = 1;
+                        self.announced = false;
+                    }
+                }
+            }
+
+            let delay = self.policy.delay(self.attempt);
+            if !self.announced {
+                self.announced = true;
+                return Some(Ok(WebSocketEvent::Reconnecting {
+                    attempt: self.attempt,
+                    delay,
+                }));
+            }
+
+            tokio::time::sleep(delay).await;
+            match self.connect().await {
+                Ok(socket) => {
+                    self.socket = Some(socket);
+                    return Some(Ok(WebSocketEvent::Reconnected));
+                }
+                Err(err) => {
+                    if self
+                        .policy
+                        .max_attempts
+                        .is_some_and(|max| self.attempt >= max)
+                    {
+                        self.done = true;
+                        return Some(Err(err));
+                    }
+                    self.attempt += 1;
+                    self.announced = false;
+                }
+            }
+        }
+    }
+}
+
+/// What happened to the playback of a session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlaybackEventKind {
+    /// The session started playing an item.
+    Start,
+    /// The playback position moved, or playback resumed after a pause.
+    Progress,
+    /// The playback was paused.
+    Pause,
+    /// The session stopped playing the item.
+    Stop,
+}
+
+/// A change in what a session is playing, as reported by `playback_events`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaybackEvent {
+    pub session_id: String,
+    pub item_id: String,
+    pub position_ticks: Option<i64>,
+    pub is_paused: bool,
+    pub event_kind: PlaybackEventKind,
+}
+
+impl PlaybackEvent {
+    fn new(session: &SessionInfo, item_id: &str, event_kind: PlaybackEventKind) -> Self {
+        Self {
+            session_id: session.id.clone(),
+            item_id: item_id.to_string(),
+            position_ticks: session.play_state.position_ticks,
+            is_paused: session.play_state.is_paused,
+            event_kind,
+        }
+    }
+}
+
+/// Turns a new snapshot of the server's sessions into playback events, by comparing it with the
+/// sessions that were playing in the previous snapshot.
+fn diff_sessions(
+    playing: &mut HashMap<String, (String, SessionInfo)>,
+    sessions: Vec<SessionInfo>,
+) -> Vec<PlaybackEvent> {
+    let mut events = Vec::new();
+    let mut still_playing = HashMap::new();
+
+    for session in sessions {
+        let Some(item_id) = session
+            .now_playing_item
+            .as_ref()
+            .map(|item| item.id.clone())
+        else {
+            continue;
+        };
+
+        let kind = match playing.remove(&session.id) {
+            Some((previous_item, previous)) if previous_item == item_id => {
+                if session.play_state.is_paused && !previous.play_state.is_paused {
+                    Some(PlaybackEventKind::Pause)
+                } else if session.play_state.is_paused != previous.play_state.is_paused
+                    || session.play_state.position_ticks != previous.play_state.position_ticks
+                {
+                    Some(PlaybackEventKind::Progress)
+                } else {
+                    None
+                }
+            }
+            Some((previous_item, previous)) => {
+                events.push(PlaybackEvent::new(
+                    &previous,
+                    &previous_item,
+                    PlaybackEventKind::Stop,
+                ));
+                Some(PlaybackEventKind::Start)
+            }
+            None => Some(PlaybackEventKind::Start),
+        };
+
+        if let Some(kind) = kind {
+            events.push(PlaybackEvent::new(&session, &item_id, kind));
+        }
+        still_playing.insert(session.id.clone(), (item_id, session));
+    }
+
+    for (item_id, session) in playing.values() {
+        events.push(PlaybackEvent::new(
+            session,
+            item_id,
+            PlaybackEventKind::Stop,
+        ));
+    }
+    *playing = still_playing;
+
+    events
+}
+
+impl JellyfinClient {
+    /// Opens a WebSocket connection to the server.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `JellyfinWebSocket` if successful, or a `JellyfinError` otherwise.
+    pub async fn connect_websocket(&self) -> Result<JellyfinWebSocket> {
+        let mut endpoint_url = self.url.join("/socket").expect("Failed to join URL");
+
+        let scheme = if endpoint_url.scheme() == "https" {
+            "wss"
+        } else {
+            "ws"
+        };
+        endpoint_url
+            .set_scheme(scheme)
+            .expect("Failed to set WebSocket scheme");
+        endpoint_url
+            .query_pairs_mut()
+            .append_pair("api_key", &self.access_token()?)
+            .append_pair("deviceId", &self.client_info().device_id);
+
+        let (stream, _) = tokio_tungstenite::connect_async(endpoint_url.as_str()).await?;
+
+        Ok(JellyfinWebSocket::new(stream))
+    }
+
+    /// Opens a WebSocket connection that reconnects when it is lost, such as when the server
+    /// restarts.
+    ///
+    /// Reconnection attempts back off as set by `policy`, and are reported as
+    /// `WebSocketEvent::Reconnecting` so the application can tell the user. When the server
+    /// rejects the token during a reconnection and auto re-authentication is enabled, the client
+    /// logs in again before retrying. The `subscriptions` are sent on every connection, including
+    /// the first one.
+    ///
+    /// # Arguments
+    ///
+    /// * `subscriptions` - Messages to send after connecting, e.g. `"SessionsStart"`.
+    /// * `policy` - How to back off between reconnection attempts.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a stream of `WebSocketEvent` instances if the first connection succeeds, or a `JellyfinError` otherwise.
+    /// The stream ends with an error once `policy.max_attempts` attempts in a row have failed.
+    /// A message that cannot be decoded is yielded as `JellyfinError::JsonError` without
+    /// reconnecting.
+    pub async fn connect_websocket_reconnecting(
+        &self,
+        subscriptions: Vec<WebSocketMessage>,
+        policy: ReconnectPolicy,
+    ) -> Result<impl Stream<Item = Result<WebSocketEvent>>> {
+        let mut state = ReconnectState {
+            client: self.clone(),
+            subscriptions,
+            policy,
+            socket: None,
+            attempt: 0,
+            announced: false,
+            done: false,
+        };
+        state.socket = Some(state.connect().await?);
+
+        Ok(stream::unfold(state, |mut state| async move {
+            let event = state.next_event().await?;
+            Some((event, state))
+        }))
+    }
+
+    /// Subscribes to playback changes across the server's sessions.
+    ///
+    /// The server is asked for session updates over a WebSocket, and the updates are turned into
+    /// typed events: one when a session starts playing an item, on every position change, when
+    /// playback is paused, and when the session stops playing the item.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping a stream of `PlaybackEvent` instances if successful, or a `JellyfinError` otherwise.
+    pub async fn playback_events(&self) -> Result<impl Stream<Item = Result<PlaybackEvent>>> {
+        let mut socket = self.connect_websocket().await?;
+        socket
+            .send(
+                "SessionsStart",
+                Value::String(format!("0,{}", SESSIONS_INTERVAL_MS)),
+            )
+            .await?;
+
+        Ok(socket
+            .scan(HashMap::new(), |playing, message| {
+                let events = match message.and_then(|message| {
+                    if message.message_type == "Sessions" {
+                        message.data_as::<Vec<SessionInfo>>().map(Some)
+                    } else {
+                        Ok(None)
+                    }
+                }) {
+                    Ok(Some(sessions)) => diff_sessions(playing, sessions)
+                        .into_iter()
+                        .map(Ok)
+                        .collect(),
+                    Ok(None) => Vec::new(),
+                    Err(err) => vec![Err(err)],
+                };
+
+                future::ready(Some(stream::iter(events)))
+            })
+            .flatten())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/websocket.rs"]
+mod tests;